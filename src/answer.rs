@@ -0,0 +1,148 @@
+use std::fmt;
+
+use serde::{Serialize, Serializer};
+
+/// A puzzle's answer. Most are numbers, some are text (e.g. day 5's crate tops) and some are a
+/// picture to be read by eye (e.g. day 10's CRT).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i64),
+    Text(String),
+    Grid(Grid),
+}
+
+/// Rows of pixels, displayed with `#` for lit pixels and `.` for dark ones.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid(Vec<Vec<bool>>);
+
+impl Grid {
+    pub fn new(rows: Vec<Vec<bool>>) -> Self {
+        Self(rows)
+    }
+
+    /// Reads a grid drawn the same way it's displayed, with `#` for lit pixels.
+    pub fn from_picture(picture: &str) -> Self {
+        Self(
+            picture
+                .lines()
+                .map(|line| line.chars().map(|c| c == '#').collect())
+                .collect(),
+        )
+    }
+
+    pub fn rows(&self) -> &[Vec<bool>] {
+        &self.0
+    }
+}
+
+impl fmt::Display for Grid {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, row) in self.0.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for &lit in row {
+                write!(f, "{}", if lit { '#' } else { '.' })?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Grid(g) => write!(f, "{}", g),
+        }
+    }
+}
+
+// Numbers serialize as numbers, everything else as its displayed text.
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Int(n) => s.serialize_i64(*n),
+            other => s.collect_str(other),
+        }
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Int(n.into())
+                }
+            }
+        )*
+    };
+}
+
+answer_from_int!(i32, u32, i64);
+
+// Anything too big for an i64 is kept exactly, as text.
+macro_rules! answer_from_wide_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    i64::try_from(n).map_or_else(|_| Answer::Text(n.to_string()), Answer::Int)
+                }
+            }
+        )*
+    };
+}
+
+answer_from_wide_int!(u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+impl From<Grid> for Answer {
+    fn from(g: Grid) -> Self {
+        Answer::Grid(g)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_converts_numbers() {
+        assert_eq!(Answer::from(-3_i32), Answer::Int(-3));
+        assert_eq!(Answer::from(24000_usize), Answer::Int(24000));
+        assert_eq!(
+            Answer::from(u64::MAX),
+            Answer::Text("18446744073709551615".to_string())
+        );
+    }
+
+    #[test]
+    fn it_displays_grids() {
+        let grid = Grid::new(vec![vec![true, false, true], vec![false, true, false]]);
+        assert_eq!(grid.to_string(), "#.#\n.#.");
+        assert_eq!(Grid::from_picture("#.#\n.#.\n"), grid);
+    }
+
+    #[test]
+    fn it_serializes_numbers_as_numbers() {
+        assert_eq!(serde_json::to_string(&Answer::Int(42)).unwrap(), "42");
+        assert_eq!(
+            serde_json::to_string(&Answer::from("CMZ")).unwrap(),
+            "\"CMZ\""
+        );
+    }
+}
@@ -0,0 +1,143 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::days::{Day, Part, Year};
+use crate::runner::{Outcome, Status};
+
+/// Known good answers, kept as they're displayed and stored as TOML with a table per year and
+/// day:
+///
+/// ```toml
+/// [2022.day01]
+/// part1 = "24000"
+/// part2 = "45000"
+/// ```
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers(BTreeMap<(Year, Day, Part), String>);
+
+type AnswersToml = BTreeMap<String, BTreeMap<String, BTreeMap<String, String>>>;
+
+impl Answers {
+    pub fn get(&self, year: Year, day: Day, part: Part) -> Option<&str> {
+        self.0.get(&(year, day, part)).map(String::as_str)
+    }
+
+    pub fn insert(&mut self, year: Year, day: Day, part: Part, answer: String) -> Option<String> {
+        self.0.insert((year, day, part), answer)
+    }
+
+    /// Loads answers from `path`, treating a missing file as having no answers yet.
+    pub fn load(path: &Path) -> Result<Self, AnswersError> {
+        match fs::read_to_string(path) {
+            Ok(s) => Self::from_toml(&s),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(AnswersError::Io(e)),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), AnswersError> {
+        fs::write(path, self.to_toml()?)?;
+        Ok(())
+    }
+
+    pub fn from_toml(s: &str) -> Result<Self, AnswersError> {
+        let tables: AnswersToml =
+            toml::from_str(s).map_err(|e| AnswersError::Format(e.to_string()))?;
+
+        let mut answers = Self::default();
+        for (year_key, days) in tables {
+            let year = parse_year(&year_key).map_err(AnswersError::Format)?;
+            for (day_key, parts) in days {
+                let day = parse_key(&day_key, "day").map_err(AnswersError::Format)?;
+                for (part_key, answer) in parts {
+                    let part = parse_key(&part_key, "part").map_err(AnswersError::Format)?;
+                    answers.insert(year, day, part, answer);
+                }
+            }
+        }
+        Ok(answers)
+    }
+
+    pub fn to_toml(&self) -> Result<String, AnswersError> {
+        let mut tables = AnswersToml::new();
+        for ((year, day, part), answer) in self.0.iter() {
+            tables
+                .entry(year.to_string())
+                .or_default()
+                .entry(format!("day{:02}", day))
+                .or_default()
+                .insert(format!("part{}", part), answer.clone());
+        }
+        toml::to_string(&tables).map_err(|e| AnswersError::Format(e.to_string()))
+    }
+}
+
+// Table keys are shared with the attempts file, which reports errors in its own terms.
+pub(crate) fn parse_year(key: &str) -> Result<Year, String> {
+    key.parse()
+        .map_err(|_| format!("expected a year like 2022, got {:?}", key))
+}
+
+pub(crate) fn parse_key(key: &str, prefix: &str) -> Result<u8, String> {
+    key.strip_prefix(prefix)
+        .and_then(|n| n.parse().ok())
+        .ok_or_else(|| format!("expected a key like {}1, got {:?}", prefix, key))
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Check {
+    Correct,
+    Incorrect { expected: String, actual: String },
+    Unrecorded,
+    NotSolved,
+}
+
+/// Compares a run's outcome against its recorded answer.
+pub fn check(answers: &Answers, outcome: &Outcome) -> Check {
+    check_expected(
+        answers.get(outcome.year, outcome.day, outcome.part),
+        outcome,
+    )
+}
+
+/// Compares a run's outcome against an expected answer, if there is one.
+pub fn check_expected(expected: Option<&str>, outcome: &Outcome) -> Check {
+    let actual = match &outcome.status {
+        Status::Solved(actual) => actual.to_string(),
+        _ => return Check::NotSolved,
+    };
+    match expected {
+        None => Check::Unrecorded,
+        Some(expected) if expected == actual => Check::Correct,
+        Some(expected) => Check::Incorrect {
+            expected: expected.to_string(),
+            actual,
+        },
+    }
+}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Io(io::Error),
+    Format(String),
+}
+
+impl fmt::Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AnswersError::Io(e) => write!(f, "couldn't access answers file: {}", e),
+            AnswersError::Format(msg) => write!(f, "invalid answers file: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {}
+
+impl From<io::Error> for AnswersError {
+    fn from(e: io::Error) -> Self {
+        AnswersError::Io(e)
+    }
+}
@@ -0,0 +1,200 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::answers::{parse_key, parse_year};
+use crate::days::{Answer, Day, Part, Year};
+
+/// Every answer tried for each day and part, with the verdict it was given, stored as TOML with
+/// a list per year, day and part:
+///
+/// ```toml
+/// [[2022.day01.part1]]
+/// answer = "71000"
+/// verdict = "too high"
+/// ```
+#[derive(Debug, Default, PartialEq)]
+pub struct Attempts(BTreeMap<(Year, Day, Part), Vec<Attempt>>);
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Verdict {
+    #[serde(rename = "correct")]
+    Correct,
+    #[serde(rename = "too high")]
+    TooHigh,
+    #[serde(rename = "too low")]
+    TooLow,
+    #[serde(rename = "wrong")]
+    Wrong,
+}
+
+type AttemptsToml = BTreeMap<String, BTreeMap<String, BTreeMap<String, Vec<Attempt>>>>;
+
+impl Attempts {
+    pub fn get(&self, year: Year, day: Day, part: Part) -> &[Attempt] {
+        self.0.get(&(year, day, part)).map_or(&[], Vec::as_slice)
+    }
+
+    /// Records `answer`'s verdict, replacing any it was given before.
+    pub fn insert(&mut self, year: Year, day: Day, part: Part, answer: String, verdict: Verdict) {
+        let attempts = self.0.entry((year, day, part)).or_default();
+        match attempts.iter_mut().find(|a| a.answer == answer) {
+            Some(attempt) => attempt.verdict = verdict,
+            None => attempts.push(Attempt { answer, verdict }),
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = ((Year, Day, Part), &[Attempt])> {
+        self.0
+            .iter()
+            .map(|(&key, attempts)| (key, attempts.as_slice()))
+    }
+
+    /// What's known to be wrong with `answer` from the attempts so far, if anything.
+    pub fn warning(&self, year: Year, day: Day, part: Part, answer: &Answer) -> Option<Warning> {
+        let attempts = self.get(year, day, part);
+        let text = answer.to_string();
+        if let Some(correct) = attempts.iter().find(|a| a.verdict == Verdict::Correct) {
+            return (correct.answer != text).then(|| Warning::NotCorrect(correct.answer.clone()));
+        }
+        if let Some(tried) = attempts.iter().find(|a| a.answer == text) {
+            return Some(Warning::Rejected(tried.verdict));
+        }
+
+        let Answer::Int(n) = *answer else {
+            return None;
+        };
+        let bound = |verdict| {
+            attempts
+                .iter()
+                .filter(move |a| a.verdict == verdict)
+                .filter_map(|a| a.answer.parse::<i64>().ok())
+        };
+        if let Some(high) = bound(Verdict::TooHigh).filter(|&high| n >= high).min() {
+            return Some(Warning::TooHigh(high));
+        }
+        if let Some(low) = bound(Verdict::TooLow).filter(|&low| n <= low).max() {
+            return Some(Warning::TooLow(low));
+        }
+        None
+    }
+
+    /// Loads attempts from `path`, treating a missing file as having none yet.
+    pub fn load(path: &Path) -> Result<Self, AttemptsError> {
+        match fs::read_to_string(path) {
+            Ok(s) => Self::from_toml(&s),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(AttemptsError::Io(e)),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), AttemptsError> {
+        fs::write(path, self.to_toml()?)?;
+        Ok(())
+    }
+
+    pub fn from_toml(s: &str) -> Result<Self, AttemptsError> {
+        let tables: AttemptsToml =
+            toml::from_str(s).map_err(|e| AttemptsError::Format(e.to_string()))?;
+
+        let mut attempts = Self::default();
+        for (year_key, days) in tables {
+            let year = parse_year(&year_key).map_err(AttemptsError::Format)?;
+            for (day_key, parts) in days {
+                let day = parse_key(&day_key, "day").map_err(AttemptsError::Format)?;
+                for (part_key, tried) in parts {
+                    let part = parse_key(&part_key, "part").map_err(AttemptsError::Format)?;
+                    for attempt in tried {
+                        attempts.insert(year, day, part, attempt.answer, attempt.verdict);
+                    }
+                }
+            }
+        }
+        Ok(attempts)
+    }
+
+    pub fn to_toml(&self) -> Result<String, AttemptsError> {
+        let mut tables = AttemptsToml::new();
+        for ((year, day, part), attempts) in self.0.iter() {
+            tables
+                .entry(year.to_string())
+                .or_default()
+                .entry(format!("day{:02}", day))
+                .or_default()
+                .insert(format!("part{}", part), attempts.clone());
+        }
+        toml::to_string(&tables).map_err(|e| AttemptsError::Format(e.to_string()))
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+        }
+    }
+}
+
+/// Why an answer is known to be wrong before it's submitted.
+#[derive(Debug, PartialEq)]
+pub enum Warning {
+    /// It was tried before, and given this verdict.
+    Rejected(Verdict),
+    /// It's no lower than an answer that was too high.
+    TooHigh(i64),
+    /// It's no higher than an answer that was too low.
+    TooLow(i64),
+    /// A different answer was correct.
+    NotCorrect(String),
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Warning::Rejected(verdict) => write!(f, "already tried, and it was {}", verdict),
+            Warning::TooHigh(high) => write!(f, "{} was already too high", high),
+            Warning::TooLow(low) => write!(f, "{} was already too low", low),
+            Warning::NotCorrect(correct) => write!(
+                f,
+                "{} was the correct answer",
+                correct.replace('\n', "\n  ")
+            ),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum AttemptsError {
+    Io(io::Error),
+    Format(String),
+}
+
+impl fmt::Display for AttemptsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AttemptsError::Io(e) => write!(f, "couldn't access attempts file: {}", e),
+            AttemptsError::Format(msg) => write!(f, "invalid attempts file: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for AttemptsError {}
+
+impl From<io::Error> for AttemptsError {
+    fn from(e: io::Error) -> Self {
+        AttemptsError::Io(e)
+    }
+}
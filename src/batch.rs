@@ -0,0 +1,95 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::days::{Params, Part, Solver};
+use crate::error::SolveError;
+use crate::runner::{self, Outcome, Status};
+
+/// Every part of a day run against one of a directory of inputs.
+#[derive(Debug)]
+pub struct BatchRun {
+    pub path: PathBuf,
+    pub outcomes: Vec<Outcome>,
+}
+
+/// Something wrong with how a part went against an input.
+#[derive(Debug, PartialEq)]
+pub enum Flag {
+    ParseFailed(Part),
+    Failed(Part),
+    Panicked(Part),
+    /// It took longer than the budget, which is given alongside.
+    OverBudget(Part, Duration, Duration),
+}
+
+/// The files in `dir`, by name, leaving out hidden files and subdirectories.
+pub fn input_files(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = vec![];
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let hidden = entry.file_name().to_string_lossy().starts_with('.');
+        if !hidden && entry.file_type()?.is_file() {
+            files.push(entry.path());
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Runs each of `solver`'s parts against every input in `dir`, one at a time so they're timed
+/// alike. Each part is given just the `params` and variant it declares.
+pub fn run_batch(solver: &dyn Solver, dir: &Path, params: &Params) -> io::Result<Vec<BatchRun>> {
+    let (year, day) = (solver.year(), solver.day());
+    Ok(input_files(dir)?
+        .into_iter()
+        .map(|path| {
+            let outcomes = solver
+                .parts()
+                .iter()
+                .map(|&part| {
+                    let params = params.for_part(solver, part);
+                    runner::run_part(year, day, part, &path, &params)
+                })
+                .collect();
+            BatchRun { path, outcomes }
+        })
+        .collect())
+}
+
+impl BatchRun {
+    /// What went wrong with each part, if anything, including taking longer than `budget`.
+    pub fn flags(&self, budget: Option<Duration>) -> Vec<Flag> {
+        let mut flags = vec![];
+        for outcome in &self.outcomes {
+            let part = outcome.part;
+            match &outcome.status {
+                Status::Failed(SolveError::Parse(_)) => flags.push(Flag::ParseFailed(part)),
+                Status::Failed(_) => flags.push(Flag::Failed(part)),
+                Status::Panicked(_) => flags.push(Flag::Panicked(part)),
+                Status::Solved(_) | Status::MissingInput(_) => {}
+            }
+            if let Some(budget) = budget.filter(|&budget| outcome.elapsed > budget) {
+                flags.push(Flag::OverBudget(part, outcome.elapsed, budget));
+            }
+        }
+        flags
+    }
+}
+
+impl fmt::Display for Flag {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Flag::ParseFailed(part) => write!(f, "part {} didn't parse", part),
+            Flag::Failed(part) => write!(f, "part {} failed", part),
+            Flag::Panicked(part) => write!(f, "part {} panicked", part),
+            Flag::OverBudget(part, elapsed, budget) => write!(
+                f,
+                "part {} took {:.2?}, over the {:.2?} budget",
+                part, elapsed, budget
+            ),
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use std::time::{Duration, Instant};
+
+use serde::{Serialize, Serializer};
+
+use crate::days::{Day, Params, Part, Solver, Year};
+use crate::error::SolveError;
+use crate::memory::{self, AllocStats};
+
+#[derive(Debug, Serialize)]
+pub struct BenchReport {
+    pub year: Year,
+    pub day: Day,
+    pub part: Part,
+    pub iterations: usize,
+    pub parse: Stats,
+    pub solve: Stats,
+    /// What one parse and solve allocated, when the `alloc-stats` feature is on.
+    pub allocs: Option<AllocStats>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Stats {
+    #[serde(rename = "min_ns", serialize_with = "as_nanos")]
+    pub min: Duration,
+    #[serde(rename = "median_ns", serialize_with = "as_nanos")]
+    pub median: Duration,
+    #[serde(rename = "mean_ns", serialize_with = "as_nanos")]
+    pub mean: Duration,
+    #[serde(rename = "p95_ns", serialize_with = "as_nanos")]
+    pub p95: Duration,
+}
+
+impl Stats {
+    /// Summarises timing samples, which must not be empty.
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        let n = samples.len();
+
+        let median = if n % 2 == 1 {
+            samples[n / 2]
+        } else {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        };
+        let p95_index = ((n as f64 * 0.95).ceil() as usize).clamp(1, n) - 1;
+
+        Self {
+            min: samples[0],
+            median,
+            mean: samples.iter().sum::<Duration>() / n as u32,
+            p95: samples[p95_index],
+        }
+    }
+}
+
+fn as_nanos<S: Serializer>(d: &Duration, s: S) -> Result<S::Ok, S::Error> {
+    s.serialize_u128(d.as_nanos())
+}
+
+// Runs `solver` over `data` for `warmup` untimed iterations, then `iterations` timed ones, timing
+// the parse and solve steps separately. The first error from either step aborts the benchmark.
+// Allocations are counted over one more, untimed, iteration.
+pub fn bench(
+    solver: &dyn Solver,
+    part: Part,
+    data: &str,
+    params: &Params,
+    warmup: usize,
+    iterations: usize,
+) -> Result<BenchReport, SolveError> {
+    if !solver.parts().contains(&part) {
+        return Err(SolveError::NotImplemented {
+            year: solver.year(),
+            day: solver.day(),
+            part,
+        });
+    }
+    let iterations = iterations.max(1);
+
+    for _ in 0..warmup {
+        let input = solver.parse(data)?;
+        solver.solve_parsed(part, &input, params)?;
+    }
+
+    let (result, allocs) = memory::measure(|| {
+        let input = solver.parse(data)?;
+        solver.solve_parsed(part, &input, params)
+    });
+    result?;
+
+    let mut parse_samples = Vec::with_capacity(iterations);
+    let mut solve_samples = Vec::with_capacity(iterations);
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let input = solver.parse(data)?;
+        let parsed = Instant::now();
+        solver.solve_parsed(part, &input, params)?;
+        let solved = Instant::now();
+
+        parse_samples.push(parsed - start);
+        solve_samples.push(solved - parsed);
+    }
+
+    Ok(BenchReport {
+        year: solver.year(),
+        day: solver.day(),
+        part,
+        iterations,
+        parse: Stats::from_samples(parse_samples),
+        solve: Stats::from_samples(solve_samples),
+        allocs: memory::enabled().then_some(allocs),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn it_summarises_samples() {
+        let stats = Stats::from_samples((1..=20).rev().map(ms).collect());
+        assert_eq!(
+            stats,
+            Stats {
+                min: ms(1),
+                median: Duration::from_micros(10_500),
+                mean: Duration::from_micros(10_500),
+                p95: ms(19),
+            }
+        );
+    }
+
+    #[test]
+    fn it_summarises_a_single_sample() {
+        let stats = Stats::from_samples(vec![ms(3)]);
+        assert_eq!(stats.min, ms(3));
+        assert_eq!(stats.median, ms(3));
+        assert_eq!(stats.p95, ms(3));
+    }
+}
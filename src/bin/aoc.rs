@@ -1,43 +1,1899 @@
-use std::path::PathBuf;
+use std::fs;
+use std::io::{self, IsTerminal};
+use std::panic;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
 
-use aoc2022::days::{Day, Part};
-use clap::Parser;
+use aoc2022::answers::{self, Answers, Check};
+use aoc2022::attempts::{Attempts, Verdict};
+use aoc2022::batch::{self, BatchRun};
+use aoc2022::bench::{self, BenchReport, Stats};
+use aoc2022::cache::Cache;
+use aoc2022::check;
+use aoc2022::config::{self, Config};
+use aoc2022::crosscheck::{self, VariantRun};
+use aoc2022::days::{self, Answer, Day, Params, Part, Year};
+use aoc2022::error::SolveError;
+use aoc2022::example;
+use aoc2022::inputs::{HttpFetch, InputStore};
+use aoc2022::memory::{self, AllocStats};
+use aoc2022::report::{Record, Summary};
+use aoc2022::runner::{self, InputInfo, Outcome, Status};
+use aoc2022::scaffold;
+use aoc2022::watch::FileWatcher;
+use clap::{ArgAction, Args, Parser, Subcommand, ValueEnum};
+use tracing::Level;
 
 #[derive(Parser)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    run: RunArgs,
+
+    /// Log solvers' intermediate steps to stderr: -v for debug events, -vv to trace them all
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    verbose: u8,
+
+    /// Write the trace as JSON lines to FILE instead of stderr
+    #[arg(long, value_name = "FILE", global = true)]
+    trace_json: Option<PathBuf>,
+
+    /// Keep inputs in ./data/PROFILE/YYYY rather than ./data/YYYY, e.g. for each account's inputs.
+    /// Defaults to the profile in aoc.toml
+    #[arg(long, value_name = "PROFILE", global = true)]
+    profile: Option<String>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a day and part, a whole day, or every implemented day
+    Run(RunArgs),
+    /// Benchmark a day's parse and solve steps against its input
+    Bench(BenchArgs),
+    /// Run a day against every input in a directory, e.g. to compare several people's inputs
+    Batch(BatchArgs),
+    /// Check answers against those recorded in the answers file
+    Verify(AnswersArgs),
+    /// Record current answers in the answers file
+    Record(AnswersArgs),
+    /// Check answers against the example inputs in ./examples
+    Example(ExampleArgs),
+    /// Check a day's input for mangling and structural problems before solving it
+    Check(CheckArgs),
+    /// Solve a day's parts with every variant, checking they agree and comparing their times
+    Crosscheck(CrosscheckArgs),
+    /// Re-run a day whenever its input or source changes
+    Watch(WatchArgs),
+    /// Create and register the module and example files for a new day
+    New(NewArgs),
+    /// Log the answers tried for a day and part, and what they were judged
+    #[command(subcommand)]
+    Attempts(AttemptsCommand),
+    /// List the implemented days and parts
+    List,
+    /// Manage the answers cached in ./.aoc-cache
+    #[command(subcommand)]
+    Cache(CacheCommand),
+    /// Fetch puzzle inputs, and list those in ./data
+    #[command(subcommand)]
+    Inputs(InputsCommand),
+    /// Show the settings from aoc.toml
+    #[command(subcommand)]
+    Config(ConfigCommand),
+}
+
+#[derive(Subcommand)]
+enum AttemptsCommand {
+    /// Record an answer that was tried, and its verdict
+    Add(AddAttemptArgs),
+    /// List the answers tried
+    List(ListAttemptsArgs),
+}
+
+#[derive(Subcommand)]
+enum CacheCommand {
+    /// Remove every cached answer
+    Clear,
+}
+
+#[derive(Subcommand)]
+enum ConfigCommand {
+    /// Print the settings in effect, from aoc.toml and the defaults
+    Show,
+}
+
+#[derive(Subcommand)]
+enum InputsCommand {
+    /// Fetch a day's input with the session token in $AOC_SESSION or aoc.toml
+    Fetch(FetchArgs),
+    /// List each implemented day's input, and when it was fetched
+    List(ListInputsArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Run every implemented day and part against its input in ./data, for every year unless
+    /// --year is given
+    #[arg(short, long, conflicts_with_all = ["day", "part", "file", "params"])]
+    all: bool,
+
+    /// Puzzle year. Defaults to the latest implemented year
+    #[arg(short, long, value_parser = clap::value_parser!(u16).range(2015..))]
+    year: Option<Year>,
+
+    /// With --all, how many days and parts to run at once. Defaults to 1
+    #[arg(short, long, conflicts_with = "day", value_parser = clap::value_parser!(u16).range(1..))]
+    jobs: Option<u16>,
+
+    #[arg(short, long, required_unless_present = "all", value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<Day>,
+
+    /// Part to run, both parts are run if omitted
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<Part>,
+
+    /// Input file, or - for stdin. Defaults to ./data/YYYY/dayNN.txt, or stdin if that's missing
+    /// and stdin is piped, otherwise it's fetched if there's a session token
+    #[arg(short, long, value_name = "FILE")]
+    file: Option<PathBuf>,
+
+    /// Override a puzzle parameter, e.g. --param row=10. See `aoc list` for each day's parameters
+    #[arg(long = "param", value_name = "KEY=VALUE", value_parser = parse_param, requires = "day")]
+    params: Vec<(String, i64)>,
+
+    /// Solve with another variant, e.g. --variant bfs. See `aoc list` for each day's variants
+    #[arg(long, value_name = "NAME", requires = "day")]
+    variant: Option<String>,
+
+    /// Defaults to the format in aoc.toml, or text
+    #[arg(long, value_enum)]
+    format: Option<Format>,
+
+    /// Solve every part, rather than reusing answers cached for the same input and build
+    #[arg(long)]
+    no_cache: bool,
+
+    /// Answers already tried, to warn about answers known to be wrong
+    #[arg(long, value_name = "FILE", default_value = ATTEMPTS_FILE)]
+    attempts: PathBuf,
+}
+
+#[derive(Clone, Copy, PartialEq, ValueEnum)]
+enum Format {
+    /// Just the answer for a single part, otherwise a table
+    Text,
+    /// A JSON object per line, and one with the totals last for --all
+    Json,
+    /// CSV with a header row, and a table of the totals after a blank line for --all
+    Csv,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// Puzzle year. Defaults to the latest implemented year
+    #[arg(short, long, value_parser = clap::value_parser!(u16).range(2015..))]
+    year: Option<Year>,
+
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Day,
+
+    /// Part to benchmark, both parts are benchmarked if omitted
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<Part>,
+
+    /// Input file, or - for stdin
+    #[arg(short, long, value_name = "FILE")]
+    file: Option<PathBuf>,
+
+    /// Override a puzzle parameter, e.g. --param row=10
+    #[arg(long = "param", value_name = "KEY=VALUE", value_parser = parse_param)]
+    params: Vec<(String, i64)>,
+
+    /// Solve with another variant, e.g. --variant bfs
+    #[arg(long, value_name = "NAME")]
+    variant: Option<String>,
+
+    /// Number of timed iterations. Defaults to the number in aoc.toml, or 100
+    #[arg(short, long)]
+    iterations: Option<usize>,
+
+    /// Number of untimed iterations to run first. Defaults to the number in aoc.toml, or 3
+    #[arg(short, long)]
+    warmup: Option<usize>,
+
+    /// Also write the results as JSON to FILE
+    #[arg(long, value_name = "FILE")]
+    json: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct ExampleArgs {
+    /// Only this year, otherwise every implemented year, or the latest if --day is given
+    #[arg(short, long, value_parser = clap::value_parser!(u16).range(2015..))]
+    year: Option<Year>,
+
+    /// Only this day, otherwise every implemented day
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<Day>,
+
+    /// Directory holding a YYYY/dayNN directory of examples for each day
+    #[arg(long, value_name = "DIR", default_value = "examples")]
+    dir: PathBuf,
+}
+
+#[derive(Args)]
+struct CheckArgs {
+    /// Puzzle year. Defaults to the latest implemented year
+    #[arg(short, long, value_parser = clap::value_parser!(u16).range(2015..))]
+    year: Option<Year>,
+
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Day,
+
+    /// Input file, or - for stdin. Defaults to ./data/YYYY/dayNN.txt
+    #[arg(value_name = "FILE")]
+    file: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct CrosscheckArgs {
+    /// Puzzle year. Defaults to the latest implemented year
+    #[arg(short, long, value_parser = clap::value_parser!(u16).range(2015..))]
+    year: Option<Year>,
+
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Day,
+
+    /// Part to check, both parts are checked if omitted
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<Part>,
+
+    /// Input file, or - for stdin. Defaults to ./data/YYYY/dayNN.txt
+    #[arg(short, long, value_name = "FILE")]
+    file: Option<PathBuf>,
+
+    /// Override a puzzle parameter, e.g. --param row=10
+    #[arg(long = "param", value_name = "KEY=VALUE", value_parser = parse_param)]
+    params: Vec<(String, i64)>,
+}
+
+#[derive(Args)]
+struct AddAttemptArgs {
+    /// Puzzle year. Defaults to the latest implemented year
+    #[arg(short, long, value_parser = clap::value_parser!(u16).range(2015..))]
+    year: Option<Year>,
+
     #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
     day: Day,
 
     #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
     part: Part,
 
+    /// The answer that was tried
+    answer: String,
+
+    #[arg(value_enum)]
+    verdict: VerdictArg,
+
+    #[arg(long, value_name = "FILE", default_value = ATTEMPTS_FILE)]
+    attempts: PathBuf,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum VerdictArg {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+}
+
+impl From<VerdictArg> for Verdict {
+    fn from(verdict: VerdictArg) -> Self {
+        match verdict {
+            VerdictArg::Correct => Verdict::Correct,
+            VerdictArg::TooHigh => Verdict::TooHigh,
+            VerdictArg::TooLow => Verdict::TooLow,
+            VerdictArg::Wrong => Verdict::Wrong,
+        }
+    }
+}
+
+#[derive(Args)]
+struct ListAttemptsArgs {
+    /// Only this year, otherwise every year, or the latest if --day is given
+    #[arg(short, long, value_parser = clap::value_parser!(u16).range(2015..))]
+    year: Option<Year>,
+
+    /// Only this day, otherwise every day
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<Day>,
+
+    #[arg(long, value_name = "FILE", default_value = ATTEMPTS_FILE)]
+    attempts: PathBuf,
+}
+
+#[derive(Args)]
+struct FetchArgs {
+    /// Puzzle year. Defaults to the latest implemented year
+    #[arg(short, long, value_parser = clap::value_parser!(u16).range(2015..))]
+    year: Option<Year>,
+
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Day,
+
+    /// Fetch it again, even if it's already in ./data
+    #[arg(long)]
+    force: bool,
+}
+
+#[derive(Args)]
+struct ListInputsArgs {
+    /// Only this year, otherwise every implemented year
+    #[arg(short, long, value_parser = clap::value_parser!(u16).range(2015..))]
+    year: Option<Year>,
+}
+
+#[derive(Args)]
+struct BatchArgs {
+    /// Puzzle year. Defaults to the latest implemented year
+    #[arg(short, long, value_parser = clap::value_parser!(u16).range(2015..))]
+    year: Option<Year>,
+
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Day,
+
+    /// Directory of inputs, each file in it is run
+    #[arg(long, value_name = "DIR")]
+    dir: PathBuf,
+
+    /// Flag parts that take longer than this, e.g. 500ms or 2s
+    #[arg(long, value_name = "DURATION", value_parser = parse_duration)]
+    budget: Option<Duration>,
+
+    /// Override a puzzle parameter, e.g. --param row=10
+    #[arg(long = "param", value_name = "KEY=VALUE", value_parser = parse_param)]
+    params: Vec<(String, i64)>,
+
+    /// Solve with another variant, e.g. --variant bfs
+    #[arg(long, value_name = "NAME")]
+    variant: Option<String>,
+
+    /// Defaults to the format in aoc.toml, or text
+    #[arg(long, value_enum)]
+    format: Option<Format>,
+}
+
+#[derive(Args)]
+struct WatchArgs {
+    /// Puzzle year. Defaults to the latest implemented year
+    #[arg(short, long, value_parser = clap::value_parser!(u16).range(2015..))]
+    year: Option<Year>,
+
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Day,
+
+    /// Input file. Defaults to ./data/YYYY/dayNN.txt
     #[arg(short, long, value_name = "FILE")]
     file: Option<PathBuf>,
+
+    /// An example input to also run against
+    #[arg(short, long, value_name = "FILE")]
+    example: Option<PathBuf>,
+
+    /// Override a puzzle parameter, e.g. --param row=10
+    #[arg(long = "param", value_name = "KEY=VALUE", value_parser = parse_param)]
+    params: Vec<(String, i64)>,
 }
 
-fn main() -> std::io::Result<()> {
-    let (day, part, file_path) = parse_args();
+#[derive(Args)]
+struct NewArgs {
+    /// Puzzle year. Defaults to the latest implemented year
+    #[arg(short, long, value_parser = clap::value_parser!(u16).range(2015..))]
+    year: Option<Year>,
+
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Day,
 
-    let output = aoc2022::run(day, part, file_path)?;
+    /// The puzzle's title. Defaults to "Day N"
+    #[arg(short, long)]
+    title: Option<String>,
+}
 
-    println!("{}", output);
+#[derive(Args)]
+struct AnswersArgs {
+    /// Only this year, otherwise every implemented year, or the latest if --day is given
+    #[arg(short, long, value_parser = clap::value_parser!(u16).range(2015..))]
+    year: Option<Year>,
 
-    Ok(())
+    /// Only this day, otherwise every implemented day
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<Day>,
+
+    #[arg(short, long, value_name = "FILE", default_value = "answers.toml")]
+    answers: PathBuf,
+
+    /// Solve every part, rather than reusing answers cached for the same input and build
+    #[arg(long)]
+    no_cache: bool,
 }
 
-fn parse_args() -> (Day, Part, PathBuf) {
+fn main() {
     let args = Cli::parse();
-    let day = args.day;
-    let part = args.part;
-    let file_path = build_file_path(args.file, day);
-    (day, part, file_path)
+    init_tracing(args.verbose, args.trace_json.as_deref());
+    let (config_path, mut config) = load_config();
+    if args.profile.is_some() {
+        config.profile = args.profile;
+    }
+    let inputs = input_store(&config);
+
+    match args.command {
+        Some(Command::Bench(bench_args)) => bench(&config, &inputs, bench_args),
+        Some(Command::Batch(batch_args)) => batch(&config, batch_args),
+        Some(Command::Verify(answers_args)) => verify(&config, &inputs, answers_args),
+        Some(Command::Record(answers_args)) => record(&config, &inputs, answers_args),
+        Some(Command::Example(example_args)) => example(example_args),
+        Some(Command::Check(check_args)) => check(&inputs, check_args),
+        Some(Command::Crosscheck(crosscheck_args)) => crosscheck(&config, &inputs, crosscheck_args),
+        Some(Command::Watch(watch_args)) => watch(&config, &inputs, watch_args),
+        Some(Command::New(new_args)) => new_day(&inputs, new_args),
+        Some(Command::List) => list(),
+        Some(Command::Attempts(AttemptsCommand::Add(add_args))) => add_attempt(add_args),
+        Some(Command::Attempts(AttemptsCommand::List(list_args))) => list_attempts(list_args),
+        Some(Command::Cache(CacheCommand::Clear)) => clear_cache(&config),
+        Some(Command::Inputs(InputsCommand::Fetch(fetch_args))) => fetch(&inputs, fetch_args),
+        Some(Command::Inputs(InputsCommand::List(list_args))) => list_inputs(&inputs, list_args),
+        Some(Command::Config(ConfigCommand::Show)) => show_config(config_path.as_deref(), &config),
+        Some(Command::Run(run_args)) => run(&config, &inputs, run_args),
+        None => run(&config, &inputs, args.run),
+    }
+}
+
+// The nearest aoc.toml and its settings, or the defaults if there isn't one.
+fn load_config() -> (Option<PathBuf>, Config) {
+    let path = std::env::current_dir()
+        .ok()
+        .and_then(|dir| Config::find(&dir));
+    let config = match &path {
+        Some(path) => Config::load(path),
+        None => Ok(Config::default()),
+    }
+    .unwrap_or_else(|e| {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    });
+    if Format::from_str(&config.format, false).is_err() {
+        eprintln!(
+            "error: invalid {}: unknown format {:?}, expected text, json or csv",
+            config::CONFIG_FILE,
+            config.format
+        );
+        process::exit(1);
+    }
+    (path, config)
+}
+
+fn config_format(config: &Config) -> Format {
+    Format::from_str(&config.format, false).expect("format is checked when aoc.toml is loaded")
+}
+
+// The session token is a password in all but name, so it's only said whether there is one.
+fn show_config(path: Option<&Path>, config: &Config) {
+    match path {
+        Some(path) => println!("# {}", path.display()),
+        None => println!("# no {} found, these are the defaults", config::CONFIG_FILE),
+    }
+    let mut config = config.clone();
+    if config.session.is_some() {
+        config.session = Some("(hidden)".to_string());
+    }
+    match config.to_toml() {
+        Ok(toml) => print!("{}", toml),
+        Err(e) => {
+            eprintln!("error: {}", e);
+            process::exit(1);
+        }
+    }
 }
 
-fn build_file_path(file_path_arg: Option<PathBuf>, day: Day) -> PathBuf {
-    if let Some(input_file) = file_path_arg.as_deref() {
-        PathBuf::from(input_file)
-    } else {
-        let day_file_name = format!("day{:02}.txt", day);
-        ["./data", day_file_name.as_str()].iter().collect()
+// Warnings only by default, so solvers' events stay out of the answers' way. A JSON trace records
+// each solve's span at least.
+fn init_tracing(verbose: u8, trace_json: Option<&Path>) {
+    let level = match verbose {
+        0 if trace_json.is_some() => Level::INFO,
+        0 => Level::WARN,
+        1 => Level::DEBUG,
+        _ => Level::TRACE,
+    };
+    let subscriber = tracing_subscriber::fmt().with_max_level(level);
+    match trace_json {
+        Some(path) => match fs::File::create(path) {
+            Ok(file) => subscriber.json().with_writer(Mutex::new(file)).init(),
+            Err(e) => {
+                eprintln!("Couldn't create {}: {}", path.display(), e);
+                process::exit(1);
+            }
+        },
+        None => subscriber
+            .with_ansi(io::stderr().is_terminal())
+            .with_writer(io::stderr)
+            .init(),
     }
 }
+
+fn run(config: &Config, inputs: &InputStore, args: RunArgs) {
+    let format = args.format.unwrap_or_else(|| config_format(config));
+    let cache = answer_cache(config, args.no_cache);
+    let cache = cache.as_ref();
+    let attempts = load_attempts(&args.attempts);
+    let Some(day) = args.day else {
+        let jobs = args.jobs.map_or(1, usize::from);
+        return run_all(config, inputs, args.year, jobs, format, cache, &attempts);
+    };
+    let year = args.year.unwrap_or_else(days::latest_year);
+    let targets = match args.part {
+        Some(part) => vec![(year, day, part)],
+        None => targets(Some(year), Some(day)),
+    };
+    let params = day_params(config, (year, day), &targets, args.params, args.variant);
+
+    match (day_input(inputs, args.file, year, day), args.part) {
+        (input, Some(part)) if format == Format::Text => {
+            let params = part_params(&params, year, day, part);
+            match (cache, input) {
+                (Some(cache), input) => {
+                    let data = read_input(input);
+                    print_cached_answer(cache, &attempts, (year, day, part), data, &params)
+                }
+                (None, Input::File(path)) => print_answer(
+                    &attempts,
+                    (year, day, part),
+                    aoc2022::run(year, day, part, path, &params),
+                ),
+                (None, Input::Stdin) => {
+                    let result = aoc2022::run_reader(year, day, part, io::stdin(), &params);
+                    print_answer(&attempts, (year, day, part), result)
+                }
+            }
+        }
+        (Input::File(path), _) => run_table(
+            targets,
+            |year, day, part| {
+                let params = part_params(&params, year, day, part);
+                match cache {
+                    Some(cache) => runner::run_part_cached(cache, year, day, part, &path, &params),
+                    None => runner::run_part(year, day, part, &path, &params),
+                }
+            },
+            format,
+            &attempts,
+        ),
+        (Input::Stdin, _) => {
+            let data = read_input(Input::Stdin);
+            run_table(
+                targets,
+                |year, day, part| {
+                    let params = part_params(&params, year, day, part);
+                    let data = data.clone();
+                    match cache {
+                        Some(cache) => {
+                            runner::run_data_cached(cache, year, day, part, data, &params)
+                        }
+                        None => runner::run_data(year, day, part, data, &params),
+                    }
+                },
+                format,
+                &attempts,
+            )
+        }
+    }
+}
+
+fn answer_cache(config: &Config, no_cache: bool) -> Option<Cache> {
+    (!no_cache).then(|| Cache::for_this_build(&config.cache_dir))
+}
+
+// Prints a single part's answer, noting on stderr if it came from the cache. A panicking solver
+// isn't caught, as it isn't without the cache.
+fn print_cached_answer(
+    cache: &Cache,
+    attempts: &Attempts,
+    (year, day, part): (Year, Day, Part),
+    data: String,
+    params: &Params,
+) {
+    let input = InputInfo::new(None, &data);
+    if let Some(answer) = cache.get(year, day, part, &input.sha256, params) {
+        println!("{}", answer);
+        eprintln!("(cached)");
+        warn_if_rejected(attempts, year, day, part, &answer);
+        return;
+    }
+
+    let result = days::run_day_part(year, day, part, data, params);
+    if let Ok(answer) = &result {
+        if let Err(e) = cache.insert(year, day, part, &input.sha256, params, answer) {
+            tracing::warn!(%e, "couldn't cache answer");
+        }
+    }
+    print_answer(attempts, (year, day, part), result)
+}
+
+fn clear_cache(config: &Config) {
+    match Cache::for_this_build(&config.cache_dir).clear() {
+        Ok(removed) => println!(
+            "removed {} cached answer{}",
+            removed,
+            if removed == 1 { "" } else { "s" }
+        ),
+        Err(e) => {
+            eprintln!("error: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+// Inputs can be fetched when there's a session token, taken from a logged in browser's cookie.
+// $AOC_SESSION takes precedence over aoc.toml, so the token needn't be kept in the file.
+fn input_store(config: &Config) -> InputStore {
+    let store = InputStore::new(&config.data_dir, config.profile.as_deref()).unwrap_or_else(|e| {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    });
+    let session = std::env::var("AOC_SESSION")
+        .ok()
+        .or_else(|| config.session.clone())
+        .filter(|session| !session.trim().is_empty());
+    match session {
+        Some(session) => store.with_fetcher(HttpFetch::new(session.trim())),
+        None => store,
+    }
+}
+
+// The path to a day's input in ./data, fetching it first if it's missing and can be fetched.
+fn stored_input(inputs: &InputStore, year: Year, day: Day) -> PathBuf {
+    inputs.ensure(year, day).unwrap_or_else(|e| {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    })
+}
+
+fn fetch(inputs: &InputStore, args: FetchArgs) {
+    let year = args.year.unwrap_or_else(days::latest_year);
+    if !inputs.can_fetch() {
+        eprintln!(
+            "error: set AOC_SESSION, or session in aoc.toml, to your adventofcode.com session \
+             cookie to fetch inputs"
+        );
+        process::exit(1);
+    }
+    let path = inputs.path(year, args.day);
+    if path.exists() && !args.force {
+        println!(
+            "{} already exists, use --force to fetch it again",
+            path.display()
+        );
+        return;
+    }
+    match inputs.fetch(year, args.day) {
+        Ok(path) => println!("wrote {}", path.display()),
+        Err(e) => {
+            eprintln!("error: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+fn list_inputs(inputs: &InputStore, args: ListInputsArgs) {
+    let mut days = days::solvers()
+        .map(|solver| (solver.year(), solver.day()))
+        .filter(|&(year, _)| args.year.is_none_or(|y| y == year))
+        .collect::<Vec<_>>();
+    days.dedup();
+
+    for (year, day) in days {
+        let path = inputs.path(year, day);
+        let detail = match (fs::read_to_string(&path), inputs.metadata(year, day)) {
+            (Err(_), _) => "missing".to_string(),
+            (_, Err(e)) => e.to_string(),
+            (Ok(_), Ok(None)) => "not fetched".to_string(),
+            (Ok(data), Ok(Some(meta))) => {
+                let sha256 = InputInfo::new(None, &data).sha256;
+                format!(
+                    "fetched {}, sha256 {}{}",
+                    meta.fetched,
+                    &meta.sha256[..meta.sha256.len().min(12)],
+                    if sha256 == meta.sha256 {
+                        ""
+                    } else {
+                        ", changed since"
+                    }
+                )
+            }
+        };
+        println!("{} day {:>2}: {}  {}", year, day, path.display(), detail);
+    }
+}
+
+fn parse_param(s: &str) -> Result<(String, i64), String> {
+    let (name, value) = s
+        .split_once('=')
+        .ok_or_else(|| format!("expected KEY=VALUE, got {:?}", s))?;
+    let value = value
+        .parse()
+        .map_err(|_| format!("expected a whole number for {}, got {:?}", name, value))?;
+    Ok((name.to_string(), value))
+}
+
+// The day's parameters from aoc.toml, overridden by any given on the command line, and the
+// variant to solve with. Those in aoc.toml are checked when it's loaded, and may be for a part
+// that isn't being run.
+fn day_params(
+    config: &Config,
+    (year, day): (Year, Day),
+    targets: &[(Year, Day, Part)],
+    overrides: Vec<(String, i64)>,
+    variant: Option<String>,
+) -> Params {
+    let overrides = check_params(overrides.into_iter().collect(), targets);
+    let params = config
+        .params(year, day)
+        .iter()
+        .chain(overrides.iter())
+        .map(|(name, value)| (name.to_string(), value))
+        .collect::<Params>();
+    match variant {
+        Some(name) => params.with_variant(check_variant(name, targets)),
+        None => params,
+    }
+}
+
+// Exits unless at least one of the `targets` can be solved with the variant `name`.
+fn check_variant(name: String, targets: &[(Year, Day, Part)]) -> String {
+    let declared = targets.iter().any(|&(year, day, part)| {
+        days::solver(year, day).is_some_and(|s| s.variants(part).iter().any(|v| v.name == name))
+    });
+    if !declared {
+        eprintln!("error: unknown variant {:?}, see `aoc list`", name);
+        process::exit(1);
+    }
+    name
+}
+
+fn parse_duration(s: &str) -> Result<Duration, String> {
+    let (number, unit) = s
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .map_or((s, ""), |i| s.split_at(i));
+    let scale = match unit {
+        "s" => 1.0,
+        "ms" => 1e-3,
+        "us" | "µs" => 1e-6,
+        _ => return Err(format!("expected a duration like 500ms or 2s, got {:?}", s)),
+    };
+    number
+        .parse::<f64>()
+        .map(|n| Duration::from_secs_f64(n * scale))
+        .map_err(|_| format!("expected a duration like 500ms or 2s, got {:?}", s))
+}
+
+// Exits if any of `params` isn't used by at least one of the `targets`.
+fn check_params(params: Params, targets: &[(Year, Day, Part)]) -> Params {
+    for (name, _) in params.iter() {
+        let declared = targets.iter().any(|&(year, day, part)| {
+            days::solver(year, day).is_some_and(|s| s.params(part).iter().any(|p| p.name == name))
+        });
+        if !declared {
+            eprintln!("error: unknown parameter {:?}, see `aoc list`", name);
+            process::exit(1);
+        }
+    }
+    params
+}
+
+// Only the `params` and variant that `part` declares, so one part's don't trip up the other's.
+fn part_params(params: &Params, year: Year, day: Day, part: Part) -> Params {
+    days::solver(year, day).map_or_else(Params::new, |s| params.for_part(s, part))
+}
+
+// Both parts of `day` if given, in `year` or the latest year, otherwise every implemented day and
+// part in `year`, or in every year.
+fn targets(year: Option<Year>, day: Option<Day>) -> Vec<(Year, Day, Part)> {
+    match day {
+        Some(day) => {
+            let year = year.unwrap_or_else(days::latest_year);
+            let parts = days::solver(year, day).map_or(&[1, 2][..], |s| s.parts());
+            parts.iter().map(|&part| (year, day, part)).collect()
+        }
+        None => runner::all_parts()
+            .into_iter()
+            .filter(|&(y, _, _)| year.is_none_or(|year| y == year))
+            .collect(),
+    }
+}
+
+fn print_answer(
+    attempts: &Attempts,
+    (year, day, part): (Year, Day, Part),
+    result: Result<Answer, SolveError>,
+) {
+    match result {
+        Ok(output) => {
+            println!("{}", output);
+            warn_if_rejected(attempts, year, day, part, &output);
+        }
+        Err(e) => {
+            eprintln!("error: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+fn run_table(
+    targets: Vec<(Year, Day, Part)>,
+    run_part: impl Fn(Year, Day, Part) -> Outcome + Sync,
+    format: Format,
+    attempts: &Attempts,
+) {
+    let outcomes = run_quietly(targets, 1, run_part);
+    print_outcomes(&outcomes, format);
+    warn_about_attempts(attempts, &outcomes);
+    exit_unless_ok(&outcomes);
+}
+
+// Runs every implemented day and part, `jobs` at a time. The time taken is reported alongside
+// the parts' times added up, which is the larger when they run in parallel.
+fn run_all(
+    config: &Config,
+    inputs: &InputStore,
+    year: Option<Year>,
+    jobs: usize,
+    format: Format,
+    cache: Option<&Cache>,
+    attempts: &Attempts,
+) {
+    let start = Instant::now();
+    let outcomes = run_quietly(targets(year, None), jobs, |year, day, part| {
+        run_from_data_dir(config, inputs, cache, year, day, part)
+    });
+    let wall_time = start.elapsed();
+
+    print_outcomes(&outcomes, format);
+    let summary = Summary::new(&outcomes, jobs, wall_time);
+    match format {
+        Format::Text => {
+            println!();
+            println!(
+                "Ran {} parts in {:.2?} over {} job{}, their times adding up to {:.2?}",
+                summary.parts,
+                wall_time,
+                jobs,
+                if jobs == 1 { "" } else { "s" },
+                outcomes.iter().map(|o| o.elapsed).sum::<Duration>()
+            );
+        }
+        Format::Json => println!("{}", summary.to_json()),
+        // a table of its own, after the records
+        Format::Csv => {
+            println!();
+            println!("{}", Summary::csv_header());
+            println!("{}", summary.to_csv());
+        }
+    }
+    warn_about_attempts(attempts, &outcomes);
+    exit_unless_ok(&outcomes);
+}
+
+fn print_outcomes(outcomes: &[Outcome], format: Format) {
+    match format {
+        Format::Text => print_table(outcomes),
+        Format::Json => {
+            for outcome in outcomes {
+                println!("{}", Record::from(outcome).to_json());
+            }
+        }
+        Format::Csv => {
+            println!("{}", Record::csv_header());
+            for outcome in outcomes {
+                println!("{}", Record::from(outcome).to_csv());
+            }
+        }
+    }
+}
+
+const ATTEMPTS_FILE: &str = "attempts.toml";
+
+fn load_attempts(path: &Path) -> Attempts {
+    Attempts::load(path).unwrap_or_else(|e| {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    })
+}
+
+// Warns on stderr about answers already known to be wrong, so they aren't submitted again.
+fn warn_if_rejected(attempts: &Attempts, year: Year, day: Day, part: Part, answer: &Answer) {
+    if let Some(warning) = attempts.warning(year, day, part, answer) {
+        eprintln!("warning: {} day {}, part {}: {}", year, day, part, warning);
+    }
+}
+
+fn warn_about_attempts(attempts: &Attempts, outcomes: &[Outcome]) {
+    for outcome in outcomes {
+        if let Status::Solved(answer) = &outcome.status {
+            warn_if_rejected(attempts, outcome.year, outcome.day, outcome.part, answer);
+        }
+    }
+}
+
+fn add_attempt(args: AddAttemptArgs) {
+    let year = args.year.unwrap_or_else(days::latest_year);
+    let mut attempts = load_attempts(&args.attempts);
+    attempts.insert(
+        year,
+        args.day,
+        args.part,
+        args.answer.trim().to_string(),
+        args.verdict.into(),
+    );
+    if let Err(e) = attempts.save(&args.attempts) {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    }
+}
+
+fn list_attempts(args: ListAttemptsArgs) {
+    let year = args.year.or(args.day.map(|_| days::latest_year()));
+    let attempts = load_attempts(&args.attempts);
+    for ((y, d, part), tried) in attempts.iter() {
+        if year.is_some_and(|year| y != year) || args.day.is_some_and(|day| d != day) {
+            continue;
+        }
+        println!("{} day {:>2}, part {}:", y, d, part);
+        for attempt in tried {
+            println!(
+                "  {}  {}",
+                attempt.answer.replace('\n', "\n  "),
+                attempt.verdict
+            );
+        }
+    }
+}
+
+fn exit_unless_ok(outcomes: &[Outcome]) {
+    if !outcomes.iter().all(Outcome::is_ok) {
+        process::exit(1);
+    }
+}
+
+fn run_quietly(
+    targets: Vec<(Year, Day, Part)>,
+    jobs: usize,
+    run_part: impl Fn(Year, Day, Part) -> Outcome + Sync,
+) -> Vec<Outcome> {
+    quietly(|| runner::run_parallel(&targets, jobs, run_part))
+}
+
+// Runs `f` without printing the messages of any panics in it, which are reported with the
+// outcomes instead, rather than also spraying them over stderr.
+fn quietly<T>(f: impl FnOnce() -> T) -> T {
+    panic::set_hook(Box::new(|_| {}));
+    let result = f();
+    let _ = panic::take_hook();
+    result
+}
+
+// Missing inputs are skipped rather than fetched, as there may be many.
+fn run_from_data_dir(
+    config: &Config,
+    inputs: &InputStore,
+    cache: Option<&Cache>,
+    year: Year,
+    day: Day,
+    part: Part,
+) -> Outcome {
+    let path = inputs.path(year, day);
+    let params = part_params(&config.params(year, day), year, day, part);
+    match cache {
+        Some(cache) => runner::run_part_cached(cache, year, day, part, &path, &params),
+        None => runner::run_part(year, day, part, &path, &params),
+    }
+}
+
+// An outcome's answer a line at a time, or a placeholder if it wasn't solved.
+fn answer_lines(outcome: &Outcome) -> Vec<String> {
+    match &outcome.status {
+        Status::Solved(answer) => answer.to_string().lines().map(String::from).collect(),
+        _ => vec!["-".to_string()],
+    }
+}
+
+fn print_table(outcomes: &[Outcome]) {
+    let answer_width = outcomes
+        .iter()
+        .flat_map(answer_lines)
+        .map(|l| l.chars().count())
+        .chain(["Answer".len()])
+        .max()
+        .unwrap_or(0);
+
+    // allocation columns are only shown when they're counted
+    let allocs = |allocs: Option<AllocStats>| match (memory::enabled(), allocs) {
+        (false, _) => String::new(),
+        (true, Some(a)) => alloc_cells(&a),
+        (true, None) => format!("{:>11}  {:>10}  {:>10}  ", "-", "-", "-"),
+    };
+
+    println!(
+        "{:>4}  {:>3}  {:>4}  {:<answer_width$}  {:>10}  {}Status",
+        "Year",
+        "Day",
+        "Part",
+        "Answer",
+        "Time",
+        if memory::enabled() {
+            format!(
+                "{:>11}  {:>10}  {:>10}  ",
+                "Allocations", "Allocated", "Peak"
+            )
+        } else {
+            String::new()
+        }
+    );
+    for outcome in outcomes {
+        let (time, status) = match &outcome.status {
+            Status::Solved(_) if outcome.cached => ("-".to_string(), "ok (cached)"),
+            Status::Solved(_) => (format!("{:.2?}", outcome.elapsed), "ok"),
+            Status::Failed(_) => (format!("{:.2?}", outcome.elapsed), "failed"),
+            Status::Panicked(_) => (format!("{:.2?}", outcome.elapsed), "panicked"),
+            Status::MissingInput(_) => ("-".to_string(), "skipped (no input)"),
+        };
+        let lines = answer_lines(outcome);
+        println!(
+            "{:>4}  {:>3}  {:>4}  {:<answer_width$}  {:>10}  {}{}",
+            outcome.year,
+            outcome.day,
+            outcome.part,
+            lines[0],
+            time,
+            allocs(outcome.allocs),
+            status
+        );
+        for line in &lines[1..] {
+            println!("{:>4}  {:>3}  {:>4}  {}", "", "", "", line);
+        }
+    }
+
+    print_errors(outcomes);
+}
+
+fn alloc_cells(allocs: &AllocStats) -> String {
+    format!(
+        "{:>11}  {:>10}  {:>10}  ",
+        allocs.allocations,
+        format_bytes(allocs.bytes),
+        format_bytes(allocs.peak_bytes)
+    )
+}
+
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", size, UNITS[unit])
+}
+
+fn label(outcome: &Outcome) -> String {
+    format!(
+        "{} day {:>2}, part {}{}",
+        outcome.year,
+        outcome.day,
+        outcome.part,
+        if outcome.cached { " (cached)" } else { "" }
+    )
+}
+
+fn print_errors<'a>(outcomes: impl IntoIterator<Item = &'a Outcome>) {
+    for outcome in outcomes {
+        let detail = match &outcome.status {
+            Status::Failed(e) => e.to_string(),
+            Status::Panicked(msg) => format!("panicked: {}", msg),
+            _ => continue,
+        };
+        eprintln!();
+        eprintln!("{}: {}", label(outcome), detail);
+    }
+}
+
+fn load_answers(path: &Path) -> Answers {
+    Answers::load(path).unwrap_or_else(|e| {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    })
+}
+
+fn verify(config: &Config, inputs: &InputStore, args: AnswersArgs) {
+    let answers = load_answers(&args.answers);
+    let cache = answer_cache(config, args.no_cache);
+    let outcomes = run_quietly(targets(args.year, args.day), 1, |year, day, part| {
+        run_from_data_dir(config, inputs, cache.as_ref(), year, day, part)
+    });
+
+    let mut failures = 0;
+    for outcome in &outcomes {
+        if !print_check(
+            &label(outcome),
+            answers::check(&answers, outcome),
+            &outcome.status,
+        ) {
+            failures += 1;
+        }
+    }
+
+    if failures > 0 {
+        eprintln!(
+            "{} of {} answers failed verification",
+            failures,
+            outcomes.len()
+        );
+        process::exit(1);
+    }
+}
+
+// Prints how a check went, returning whether it passed.
+fn print_check(label: &str, check: Check, status: &Status) -> bool {
+    match (check, status) {
+        (Check::Correct, _) => println!("{}: ok", label),
+        (Check::Unrecorded, _) => println!("{}: no recorded answer", label),
+        (Check::Incorrect { expected, actual }, _) => {
+            println!("{}: WRONG", label);
+            println!("  expected: {}", expected.replace('\n', "\n            "));
+            println!("  actual:   {}", actual.replace('\n', "\n            "));
+            return false;
+        }
+        (Check::NotSolved, Status::MissingInput(path)) => {
+            println!("{}: skipped, no input at {}", label, path.display())
+        }
+        (Check::NotSolved, Status::Failed(e)) => {
+            println!("{}: FAILED {}", label, e);
+            return false;
+        }
+        (Check::NotSolved, Status::Panicked(msg)) => {
+            println!("{}: PANICKED {}", label, msg);
+            return false;
+        }
+        (Check::NotSolved, Status::Solved(_)) => unreachable!("solved outcomes are checked"),
+    }
+    true
+}
+
+fn example(args: ExampleArgs) {
+    let examples = match args.day {
+        Some(day) => {
+            let year = args.year.unwrap_or_else(days::latest_year);
+            example::day_examples(&args.dir, year, day)
+        }
+        None => example::all_examples(&args.dir).map(|examples| {
+            examples
+                .into_iter()
+                .filter(|e| args.year.is_none_or(|year| e.year == year))
+                .collect()
+        }),
+    }
+    .unwrap_or_else(|e| {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    });
+    if examples.is_empty() {
+        eprintln!("error: no examples found in {}", args.dir.display());
+        process::exit(1);
+    }
+
+    let mut checks = 0;
+    let mut failures = 0;
+    for example in &examples {
+        let targets = targets(Some(example.year), Some(example.day));
+        let outcomes = run_quietly(targets, 1, |_, _, part| example.run(part));
+        for outcome in &outcomes {
+            let label = format!("{}, {}", label(outcome), example.name);
+            checks += 1;
+            if !print_check(&label, example.check(outcome), &outcome.status) {
+                failures += 1;
+            }
+        }
+    }
+
+    if failures > 0 {
+        eprintln!("{} of {} examples failed", failures, checks);
+        process::exit(1);
+    }
+}
+
+fn record(config: &Config, inputs: &InputStore, args: AnswersArgs) {
+    let mut answers = load_answers(&args.answers);
+    let cache = answer_cache(config, args.no_cache);
+    let outcomes = run_quietly(targets(args.year, args.day), 1, |year, day, part| {
+        run_from_data_dir(config, inputs, cache.as_ref(), year, day, part)
+    });
+
+    for outcome in outcomes {
+        let label = label(&outcome);
+        match outcome.status {
+            Status::Solved(answer) => {
+                let answer = answer.to_string();
+                match answers.insert(outcome.year, outcome.day, outcome.part, answer.clone()) {
+                    Some(previous) if previous != answer => println!("{}: changed", label),
+                    Some(_) => println!("{}: unchanged", label),
+                    None => println!("{}: recorded", label),
+                }
+            }
+            Status::MissingInput(_) => println!("{}: skipped, no input", label),
+            Status::Failed(e) => println!("{}: not recorded, {}", label, e),
+            Status::Panicked(msg) => println!("{}: not recorded, panicked: {}", label, msg),
+        }
+    }
+
+    if let Err(e) = answers.save(&args.answers) {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    }
+}
+
+fn check(inputs: &InputStore, args: CheckArgs) {
+    let year = args.year.unwrap_or_else(days::latest_year);
+    let solver = days::solver(year, args.day).unwrap_or_else(|| {
+        eprintln!("error: {} day {} is not implemented", year, args.day);
+        process::exit(1);
+    });
+    let (name, input) = match args.file {
+        Some(path) if path.as_os_str() == "-" => ("stdin".to_string(), Input::Stdin),
+        file => {
+            let path = file.unwrap_or_else(|| stored_input(inputs, year, args.day));
+            (path.display().to_string(), Input::File(path))
+        }
+    };
+
+    let problems = check::check_input(solver, &read_input(input));
+    if problems.is_empty() {
+        println!("{}: ok", name);
+        return;
+    }
+    for problem in &problems {
+        println!("{}: {}", name, problem);
+        println!();
+    }
+    eprintln!(
+        "{} problem{} found",
+        problems.len(),
+        if problems.len() == 1 { "" } else { "s" }
+    );
+    process::exit(1);
+}
+
+fn crosscheck(config: &Config, inputs: &InputStore, args: CrosscheckArgs) {
+    let year = args.year.unwrap_or_else(days::latest_year);
+    let solver = days::solver(year, args.day).unwrap_or_else(|| {
+        eprintln!("error: {} day {} is not implemented", year, args.day);
+        process::exit(1);
+    });
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => solver.parts().to_vec(),
+    };
+    let targets = parts
+        .iter()
+        .map(|&part| (year, args.day, part))
+        .collect::<Vec<_>>();
+    let params = day_params(config, (year, args.day), &targets, args.params, None);
+    let data = read_input(day_input(inputs, args.file, year, args.day));
+
+    let runs = quietly(|| {
+        parts
+            .iter()
+            .map(|&part| crosscheck::crosscheck(solver, part, &data, &params))
+            .collect::<Vec<_>>()
+    });
+
+    print_variant_runs(&runs);
+    let disagreements = runs.iter().filter(|runs| !crosscheck::agree(runs)).count();
+    if disagreements > 0 {
+        eprintln!(
+            "{} of {} parts didn't agree across variants",
+            disagreements,
+            runs.len()
+        );
+        process::exit(1);
+    }
+}
+
+fn print_variant_runs(runs: &[Vec<VariantRun>]) {
+    let variant_name = |run: &VariantRun| run.variant.unwrap_or("(default)");
+    let all = runs.iter().flatten().collect::<Vec<_>>();
+    let name_width = all
+        .iter()
+        .map(|run| variant_name(run).len())
+        .chain(["Variant".len()])
+        .max()
+        .unwrap_or(0);
+    let answer_width = all
+        .iter()
+        .flat_map(|run| answer_lines(&run.outcome))
+        .map(|l| l.chars().count())
+        .chain(["Answer".len()])
+        .max()
+        .unwrap_or(0);
+
+    println!(
+        "{:>4}  {:<name_width$}  {:<answer_width$}  {:>10}  Status",
+        "Part", "Variant", "Answer", "Time"
+    );
+    for part_runs in runs {
+        let agree = crosscheck::agree(part_runs);
+        for run in part_runs {
+            let outcome = &run.outcome;
+            let status = match &outcome.status {
+                Status::Solved(_) if agree => "ok",
+                Status::Solved(_) => "DISAGREES",
+                Status::Failed(_) => "failed",
+                Status::Panicked(_) => "panicked",
+                Status::MissingInput(_) => "skipped (no input)",
+            };
+            let lines = answer_lines(outcome);
+            println!(
+                "{:>4}  {:<name_width$}  {:<answer_width$}  {:>10}  {}",
+                outcome.part,
+                variant_name(run),
+                lines[0],
+                format!("{:.2?}", outcome.elapsed),
+                status
+            );
+            for line in &lines[1..] {
+                println!("{:>4}  {:<name_width$}  {}", "", "", line);
+            }
+        }
+    }
+
+    for run in all {
+        let detail = match &run.outcome.status {
+            Status::Failed(e) => e.to_string(),
+            Status::Panicked(msg) => format!("panicked: {}", msg),
+            _ => continue,
+        };
+        eprintln!();
+        eprintln!(
+            "part {}, {}: {}",
+            run.outcome.part,
+            variant_name(run),
+            detail
+        );
+    }
+}
+
+fn bench(config: &Config, inputs: &InputStore, args: BenchArgs) {
+    let year = args.year.unwrap_or_else(days::latest_year);
+    let solver = days::solver(year, args.day).unwrap_or_else(|| {
+        eprintln!("error: {} day {} is not implemented", year, args.day);
+        process::exit(1);
+    });
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => solver.parts().to_vec(),
+    };
+
+    let targets = parts
+        .iter()
+        .map(|&part| (year, args.day, part))
+        .collect::<Vec<_>>();
+    let params = day_params(
+        config,
+        (year, args.day),
+        &targets,
+        args.params,
+        args.variant,
+    );
+    let data = read_input(day_input(inputs, args.file, year, args.day));
+    let iterations = args.iterations.unwrap_or(config.bench.iterations);
+    let warmup = args.warmup.unwrap_or(config.bench.warmup);
+
+    let mut reports = vec![];
+    for part in parts {
+        let params = part_params(&params, year, args.day, part);
+        match bench::bench(solver, part, &data, &params, warmup, iterations) {
+            Ok(report) => reports.push(report),
+            Err(e) => {
+                eprintln!("error: {}", e);
+                process::exit(1);
+            }
+        }
+    }
+
+    print_bench_table(&reports);
+
+    if let Some(path) = args.json {
+        let json = serde_json::to_string_pretty(&reports).expect("reports serialize to JSON");
+        if let Err(e) = fs::write(&path, json + "\n") {
+            eprintln!("error: couldn't write {}: {}", path.display(), e);
+            process::exit(1);
+        }
+    }
+}
+
+fn print_bench_table(reports: &[BenchReport]) {
+    println!(
+        "{:>4}  {:>3}  {:>4}  {:<5}  {:>10}  {:>10}  {:>10}  {:>10}",
+        "Year", "Day", "Part", "Step", "Min", "Median", "Mean", "p95"
+    );
+    let row = |report: &BenchReport, step: &str, stats: &Stats| {
+        let fmt = |d: Duration| format!("{:.2?}", d);
+        println!(
+            "{:>4}  {:>3}  {:>4}  {:<5}  {:>10}  {:>10}  {:>10}  {:>10}",
+            report.year,
+            report.day,
+            report.part,
+            step,
+            fmt(stats.min),
+            fmt(stats.median),
+            fmt(stats.mean),
+            fmt(stats.p95)
+        );
+    };
+    for report in reports {
+        row(report, "parse", &report.parse);
+        row(report, "solve", &report.solve);
+    }
+
+    if reports.iter().any(|r| r.allocs.is_some()) {
+        println!();
+        println!(
+            "{:>4}  {:>3}  {:>4}  {:>11}  {:>10}  {:>10}",
+            "Year", "Day", "Part", "Allocations", "Allocated", "Peak"
+        );
+        for report in reports {
+            if let Some(allocs) = &report.allocs {
+                println!(
+                    "{:>4}  {:>3}  {:>4}  {}",
+                    report.year,
+                    report.day,
+                    report.part,
+                    alloc_cells(allocs).trim_end()
+                );
+            }
+        }
+    }
+}
+
+fn batch(config: &Config, args: BatchArgs) {
+    let year = args.year.unwrap_or_else(days::latest_year);
+    let solver = days::solver(year, args.day).unwrap_or_else(|| {
+        eprintln!("error: {} day {} is not implemented", year, args.day);
+        process::exit(1);
+    });
+    let format = args.format.unwrap_or_else(|| config_format(config));
+    let targets = targets(Some(year), Some(args.day));
+    let params = day_params(
+        config,
+        (year, args.day),
+        &targets,
+        args.params,
+        args.variant,
+    );
+
+    let runs = quietly(|| batch::run_batch(solver, &args.dir, &params)).unwrap_or_else(|e| {
+        eprintln!("error: couldn't read {}: {}", args.dir.display(), e);
+        process::exit(1);
+    });
+    if runs.is_empty() {
+        eprintln!("error: no inputs found in {}", args.dir.display());
+        process::exit(1);
+    }
+
+    let flagged = runs.iter().any(|run| !run.flags(args.budget).is_empty());
+    match format {
+        Format::Text => print_batch(&runs, args.budget),
+        _ => {
+            for run in &runs {
+                for flag in run.flags(args.budget) {
+                    eprintln!("{}: {}", run.path.display(), flag);
+                }
+            }
+            let outcomes = runs
+                .into_iter()
+                .flat_map(|run| run.outcomes)
+                .collect::<Vec<_>>();
+            print_outcomes(&outcomes, format);
+        }
+    }
+    if flagged {
+        process::exit(1);
+    }
+}
+
+// A row per input with each part's answer and time side by side, then what went wrong.
+fn print_batch(runs: &[BatchRun], budget: Option<Duration>) {
+    let name = |run: &BatchRun| {
+        run.path.file_name().map_or_else(
+            || run.path.display().to_string(),
+            |n| n.to_string_lossy().into(),
+        )
+    };
+    let name_width = runs
+        .iter()
+        .map(|run| name(run).chars().count())
+        .chain(["Input".len()])
+        .max()
+        .unwrap_or(0);
+    let parts = runs[0].outcomes.iter().map(|o| o.part).collect::<Vec<_>>();
+    let answer_widths = (0..parts.len())
+        .map(|i| {
+            runs.iter()
+                .flat_map(|run| answer_lines(&run.outcomes[i]))
+                .map(|l| l.chars().count())
+                .chain(["Part N".len()])
+                .max()
+                .unwrap_or(0)
+        })
+        .collect::<Vec<_>>();
+
+    let mut header = format!("{:<name_width$}", "Input");
+    for (part, width) in parts.iter().zip(&answer_widths) {
+        header += &format!("  {:<width$}  {:>10}", format!("Part {}", part), "Time");
+    }
+    println!("{}  Flags", header);
+
+    for run in runs {
+        let answers = run.outcomes.iter().map(answer_lines).collect::<Vec<_>>();
+        let flags = run
+            .flags(budget)
+            .iter()
+            .map(|flag| flag.to_string())
+            .collect::<Vec<_>>();
+        let height = answers.iter().map(Vec::len).max().unwrap_or(1);
+        for line in 0..height {
+            let mut row = format!(
+                "{:<name_width$}",
+                if line == 0 { name(run) } else { String::new() }
+            );
+            for ((outcome, lines), width) in run.outcomes.iter().zip(&answers).zip(&answer_widths) {
+                let time = match (&outcome.status, line) {
+                    (Status::MissingInput(_), _) | (_, 1..) => String::new(),
+                    _ => format!("{:.2?}", outcome.elapsed),
+                };
+                let answer = lines.get(line).map_or("", String::as_str);
+                row += &format!("  {:<width$}  {:>10}", answer, time);
+            }
+            if line == 0 {
+                row += &format!("  {}", flags.join(", "));
+            }
+            println!("{}", row.trim_end());
+        }
+    }
+
+    for run in runs {
+        for outcome in &run.outcomes {
+            let detail = match &outcome.status {
+                Status::Failed(e) => e.to_string(),
+                Status::Panicked(msg) => format!("panicked: {}", msg),
+                _ => continue,
+            };
+            eprintln!();
+            eprintln!("{}, part {}: {}", name(run), outcome.part, detail);
+        }
+    }
+}
+
+// How long to wait for an editor to finish saving before re-running.
+const WATCH_SETTLE: Duration = Duration::from_millis(100);
+
+fn watch(config: &Config, inputs: &InputStore, args: WatchArgs) {
+    let year = args.year.unwrap_or_else(days::latest_year);
+    if days::solver(year, args.day).is_none() {
+        eprintln!("error: {} day {} is not implemented", year, args.day);
+        process::exit(1);
+    }
+    let targets = targets(Some(year), Some(args.day));
+    let params = day_params(config, (year, args.day), &targets, args.params, None);
+
+    let inputs = [
+        Some(
+            args.file
+                .unwrap_or_else(|| stored_input(inputs, year, args.day)),
+        ),
+        args.example,
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<PathBuf>>();
+    let source = Some(day_source_path(year, args.day)).filter(|path| path.exists());
+    let watched = inputs
+        .iter()
+        .chain(&source)
+        .cloned()
+        .collect::<Vec<PathBuf>>();
+    let watcher = FileWatcher::new(&watched).unwrap_or_else(|e| {
+        eprintln!("error: couldn't watch for changes: {}", e);
+        process::exit(1);
+    });
+
+    let mut previous: Option<Vec<(PathBuf, Outcome)>> = None;
+    loop {
+        let outcomes = inputs
+            .iter()
+            .flat_map(|path| {
+                let outcomes = run_quietly(targets.clone(), 1, |year, day, part| {
+                    let params = part_params(&params, year, day, part);
+                    runner::run_part(year, day, part, path, &params)
+                });
+                outcomes.into_iter().map(move |o| (path.clone(), o))
+            })
+            .collect::<Vec<(PathBuf, Outcome)>>();
+        print_watch_table(&outcomes, previous.as_deref());
+        previous = Some(outcomes);
+
+        println!();
+        println!(
+            "Watching {} for changes...",
+            watched
+                .iter()
+                .map(|p| p.display().to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        );
+        let changed = watcher.wait(WATCH_SETTLE).unwrap_or_else(|e| {
+            eprintln!("error: stopped watching for changes: {}", e);
+            process::exit(1);
+        });
+        println!();
+        for path in &changed {
+            println!("{} changed", path.display());
+        }
+        if source.as_ref().is_some_and(|s| changed.contains(s)) {
+            rebuild_and_restart();
+        }
+    }
+}
+
+struct WatchRow {
+    input: String,
+    part: Part,
+    answer: Vec<String>,
+    time: String,
+    previous: Vec<String>,
+    previous_time: String,
+    status: &'static str,
+}
+
+// Prints each input's answers next to those from the previous run, marking any that changed.
+fn print_watch_table(outcomes: &[(PathBuf, Outcome)], previous: Option<&[(PathBuf, Outcome)]>) {
+    let time = |o: &Outcome| match o.status {
+        Status::MissingInput(_) => "-".to_string(),
+        _ => format!("{:.2?}", o.elapsed),
+    };
+
+    let rows = outcomes
+        .iter()
+        .map(|(path, outcome)| {
+            let before = previous.and_then(|previous| {
+                previous
+                    .iter()
+                    .find(|(p, o)| p == path && o.part == outcome.part)
+                    .map(|(_, o)| o)
+            });
+            let status = match (&outcome.status, before.map(|o| &o.status)) {
+                (Status::Solved(a), Some(Status::Solved(b))) if a == b => "same",
+                (Status::Solved(_), Some(_)) => "changed",
+                (Status::Solved(_), None) => "ok",
+                (Status::Failed(_), _) => "failed",
+                (Status::Panicked(_), _) => "panicked",
+                (Status::MissingInput(_), _) => "skipped (no input)",
+            };
+            WatchRow {
+                input: path.file_name().map_or_else(
+                    || path.display().to_string(),
+                    |name| name.to_string_lossy().into(),
+                ),
+                part: outcome.part,
+                answer: answer_lines(outcome),
+                time: time(outcome),
+                previous: before.map_or_else(|| vec!["-".to_string()], answer_lines),
+                previous_time: before.map_or_else(|| "-".to_string(), time),
+                status,
+            }
+        })
+        .collect::<Vec<WatchRow>>();
+
+    let width = |header: &str, cells: Vec<&String>| {
+        cells
+            .iter()
+            .map(|c| c.chars().count())
+            .chain([header.len()])
+            .max()
+            .unwrap_or(0)
+    };
+    let input_width = width("Input", rows.iter().map(|r| &r.input).collect());
+    let answer_width = width("Answer", rows.iter().flat_map(|r| &r.answer).collect());
+    let previous_width = width("Previous", rows.iter().flat_map(|r| &r.previous).collect());
+    let highlight = io::stdout().is_terminal();
+
+    println!(
+        "{:<input_width$}  {:>4}  {:<answer_width$}  {:>10}  {:<previous_width$}  {:>10}  Status",
+        "Input", "Part", "Answer", "Time", "Previous", "Time"
+    );
+    for row in &rows {
+        let status = if highlight && row.status == "changed" {
+            format!("\x1b[1;33m{}\x1b[0m", row.status)
+        } else {
+            row.status.to_string()
+        };
+        println!(
+            "{:<input_width$}  {:>4}  {:<answer_width$}  {:>10}  {:<previous_width$}  {:>10}  {}",
+            row.input,
+            row.part,
+            row.answer[0],
+            row.time,
+            row.previous[0],
+            row.previous_time,
+            status
+        );
+        let line = |lines: &[String], i: usize| lines.get(i).cloned().unwrap_or_default();
+        for i in 1..row.answer.len().max(row.previous.len()) {
+            println!(
+                "{:<input_width$}  {:>4}  {:<answer_width$}  {:>10}  {}",
+                "",
+                "",
+                line(&row.answer, i),
+                "",
+                line(&row.previous, i)
+            );
+        }
+    }
+
+    print_errors(outcomes.iter().map(|(_, o)| o));
+}
+
+// Where a day's solution lives in the checkout this binary was built from.
+fn day_source_path(year: Year, day: Day) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src")
+        .join(format!("year{}", year))
+        .join(format!("day{:02}.rs", day))
+}
+
+// Rebuilds the binary and replaces this process with the new one, keeping the same arguments. If
+// the build fails we carry on watching, so fixing the error triggers another attempt.
+fn rebuild_and_restart() {
+    // looked up before building, as once the binary is replaced Linux reports it as deleted
+    let exe = std::env::current_exe().unwrap_or_else(|e| {
+        eprintln!("error: couldn't find the aoc binary to restart: {}", e);
+        process::exit(1);
+    });
+    let manifest = Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml");
+    let mut build = process::Command::new(env!("CARGO"));
+    build.args(["build", "--quiet", "--bin", "aoc", "--manifest-path"]);
+    build.arg(&manifest);
+    if !cfg!(debug_assertions) {
+        build.arg("--release");
+    }
+
+    println!("Rebuilding...");
+    match build.status() {
+        Ok(status) if status.success() => {}
+        Ok(_) => return eprintln!("error: build failed, waiting for another change"),
+        Err(e) => return eprintln!("error: couldn't run cargo: {}", e),
+    }
+
+    let mut restart = process::Command::new(exe);
+    restart.args(std::env::args_os().skip(1));
+    restart_with(restart);
+}
+
+#[cfg(unix)]
+fn restart_with(mut command: process::Command) {
+    use std::os::unix::process::CommandExt;
+
+    let e = command.exec();
+    eprintln!("error: couldn't restart: {}", e);
+    process::exit(1);
+}
+
+#[cfg(not(unix))]
+fn restart_with(mut command: process::Command) {
+    match command.status() {
+        Ok(status) => process::exit(status.code().unwrap_or(1)),
+        Err(e) => {
+            eprintln!("error: couldn't restart: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+fn new_day(inputs: &InputStore, args: NewArgs) {
+    let year = args.year.unwrap_or_else(days::latest_year);
+    let title = args.title.unwrap_or_else(|| format!("Day {}", args.day));
+    match scaffold::new_day(Path::new("."), year, args.day, &title) {
+        Ok(written) => {
+            for path in written {
+                println!("wrote {}", path.display());
+            }
+            println!(
+                "put your input in {}",
+                inputs.path(year, args.day).display()
+            );
+        }
+        Err(e) => {
+            eprintln!("error: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+fn list() {
+    for solver in days::solvers() {
+        let parts = solver
+            .parts()
+            .iter()
+            .map(|p| p.to_string())
+            .collect::<Vec<String>>()
+            .join(", ");
+        println!(
+            "{} day {:>2}: {} (parts: {})",
+            solver.year(),
+            solver.day(),
+            solver.title(),
+            parts
+        );
+        for &part in solver.parts() {
+            for param in solver.params(part) {
+                println!(
+                    "             part {} --param {}={}  {}",
+                    part, param.name, param.default, param.help
+                );
+            }
+            for variant in solver.variants(part) {
+                println!(
+                    "             part {} --variant {}  {}",
+                    part, variant.name, variant.help
+                );
+            }
+        }
+    }
+}
+
+enum Input {
+    File(PathBuf),
+    Stdin,
+}
+
+// `--file -` reads stdin, as does having no data file for the day when stdin is piped.
+fn day_input(inputs: &InputStore, file_path_arg: Option<PathBuf>, year: Year, day: Day) -> Input {
+    match file_path_arg {
+        Some(path) if path.as_os_str() == "-" => Input::Stdin,
+        Some(path) => Input::File(path),
+        None => {
+            if !inputs.path(year, day).exists() && !io::stdin().is_terminal() {
+                Input::Stdin
+            } else {
+                Input::File(stored_input(inputs, year, day))
+            }
+        }
+    }
+}
+
+fn read_input(input: Input) -> String {
+    let data = match input {
+        Input::File(path) => aoc2022::load_data(path),
+        Input::Stdin => aoc2022::read_data(io::stdin()),
+    };
+    data.unwrap_or_else(|e| {
+        eprintln!("error: couldn't read input: {}", e);
+        process::exit(1);
+    })
+}
@@ -0,0 +1,231 @@
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::time::UNIX_EPOCH;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::answer::Grid;
+use crate::days::{Answer, Day, Params, Part, Year};
+
+/// Answers already worked out, kept on disk so slow days needn't be solved again just to read
+/// them. An answer is only reused for the same day, part, input and parameters, solved by the
+/// same build, so changing a solver invalidates what it's cached.
+#[derive(Debug)]
+pub struct Cache {
+    dir: PathBuf,
+    build: String,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Key {
+    build: String,
+    year: Year,
+    day: Day,
+    part: Part,
+    input_sha256: String,
+    params: Vec<(String, i64)>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    variant: Option<String>,
+}
+
+// What's stored for each answer, with the key it was stored under to check against.
+#[derive(Debug, Serialize, Deserialize)]
+struct Entry {
+    #[serde(flatten)]
+    key: Key,
+    answer: StoredAnswer,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum StoredAnswer {
+    Int(i64),
+    Text(String),
+    Grid(String),
+}
+
+impl Cache {
+    pub fn new(dir: impl Into<PathBuf>, build: impl Into<String>) -> Self {
+        Self {
+            dir: dir.into(),
+            build: build.into(),
+        }
+    }
+
+    /// A cache in `dir` for answers from the running executable.
+    pub fn for_this_build(dir: impl Into<PathBuf>) -> Self {
+        Self::new(dir, build_id())
+    }
+
+    /// The cached answer, if there is one. Unreadable entries are treated as missing.
+    pub fn get(
+        &self,
+        year: Year,
+        day: Day,
+        part: Part,
+        input_sha256: &str,
+        params: &Params,
+    ) -> Option<Answer> {
+        let key = self.key(year, day, part, input_sha256, params);
+        let path = self.path(&key);
+        let stored = fs::read_to_string(&path).ok()?;
+        match serde_json::from_str::<Entry>(&stored) {
+            Ok(found) if found.key == key => Some(found.answer.into()),
+            Ok(_) => None,
+            Err(e) => {
+                tracing::debug!(path = %path.display(), %e, "ignoring unreadable cache entry");
+                None
+            }
+        }
+    }
+
+    pub fn insert(
+        &self,
+        year: Year,
+        day: Day,
+        part: Part,
+        input_sha256: &str,
+        params: &Params,
+        answer: &Answer,
+    ) -> Result<(), CacheError> {
+        let entry = Entry {
+            key: self.key(year, day, part, input_sha256, params),
+            answer: answer.into(),
+        };
+        let json = serde_json::to_string(&entry).map_err(|e| CacheError::Format(e.to_string()))?;
+        fs::create_dir_all(&self.dir)?;
+        fs::write(self.path(&entry.key), json)?;
+        Ok(())
+    }
+
+    /// Removes every cached answer, from any build. Returns how many there were.
+    pub fn clear(&self) -> Result<usize, CacheError> {
+        let entries = match fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(0),
+            Err(e) => return Err(e.into()),
+        };
+        let mut removed = 0;
+        for entry in entries {
+            let path = entry?.path();
+            if path.extension().is_some_and(|ext| ext == "json") {
+                fs::remove_file(path)?;
+                removed += 1;
+            }
+        }
+        // leave the directory be if something else was put in it
+        let _ = fs::remove_dir(&self.dir);
+        Ok(removed)
+    }
+
+    fn key(&self, year: Year, day: Day, part: Part, input_sha256: &str, params: &Params) -> Key {
+        Key {
+            build: self.build.clone(),
+            year,
+            day,
+            part,
+            input_sha256: input_sha256.to_string(),
+            params: params
+                .iter()
+                .map(|(name, value)| (name.to_string(), value))
+                .collect(),
+            variant: params.variant().map(String::from),
+        }
+    }
+
+    fn path(&self, key: &Key) -> PathBuf {
+        self.dir
+            .join(format!("{}.json", hex_sha256(&key.to_string())))
+    }
+}
+
+impl fmt::Display for Key {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let params = self
+            .params
+            .iter()
+            .map(|(name, value)| format!("{}={}", name, value))
+            .collect::<Vec<String>>()
+            .join(",");
+        write!(
+            f,
+            "{}\n{}\n{}\n{}\n{}\n{}",
+            self.build, self.year, self.day, self.part, self.input_sha256, params
+        )?;
+        match &self.variant {
+            Some(variant) => write!(f, "\n{}", variant),
+            None => Ok(()),
+        }
+    }
+}
+
+impl From<&Answer> for StoredAnswer {
+    fn from(answer: &Answer) -> Self {
+        match answer {
+            Answer::Int(n) => StoredAnswer::Int(*n),
+            Answer::Text(s) => StoredAnswer::Text(s.clone()),
+            Answer::Grid(g) => StoredAnswer::Grid(g.to_string()),
+        }
+    }
+}
+
+impl From<StoredAnswer> for Answer {
+    fn from(stored: StoredAnswer) -> Self {
+        match stored {
+            StoredAnswer::Int(n) => Answer::Int(n),
+            StoredAnswer::Text(s) => Answer::Text(s),
+            StoredAnswer::Grid(picture) => Answer::Grid(Grid::from_picture(&picture)),
+        }
+    }
+}
+
+// Identifies the running executable by its version, size and modification time, which change
+// whenever it's rebuilt.
+fn build_id() -> String {
+    let exe = env::current_exe()
+        .and_then(fs::metadata)
+        .map(|m| {
+            let modified = m
+                .modified()
+                .ok()
+                .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+                .map_or(0, |d| d.as_nanos());
+            format!("{}-{}", m.len(), modified)
+        })
+        .unwrap_or_default();
+    format!("{}-{}", env!("CARGO_PKG_VERSION"), exe)
+}
+
+fn hex_sha256(s: &str) -> String {
+    Sha256::digest(s.as_bytes())
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+#[derive(Debug)]
+pub enum CacheError {
+    Io(io::Error),
+    Format(String),
+}
+
+impl fmt::Display for CacheError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CacheError::Io(e) => write!(f, "couldn't access answer cache: {}", e),
+            CacheError::Format(msg) => write!(f, "invalid answer cache entry: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for CacheError {}
+
+impl From<io::Error> for CacheError {
+    fn from(e: io::Error) -> Self {
+        CacheError::Io(e)
+    }
+}
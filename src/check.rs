@@ -0,0 +1,137 @@
+use crate::days::Solver;
+use crate::error::{ParseError, SolveError};
+
+/// Everything wrong with `data` as input for `solver`: signs of mangling that parsers put up
+/// with, such as CRLF line endings and trailing whitespace, and the puzzle's own structural
+/// problems. The parser's error is only reported when nothing more specific was found, as it's
+/// usually a symptom of one of those.
+pub fn check_input(solver: &dyn Solver, data: &str) -> Vec<ParseError> {
+    let mut problems = solver.check(data);
+    if !problems.is_empty() {
+        // in the order they appear, with those that aren't anywhere in particular last
+        problems.sort_by_key(|p| {
+            p.location
+                .as_ref()
+                .map_or((usize::MAX, 0), |l| (l.line, l.column))
+        });
+        return problems;
+    }
+    match solver.parse(data) {
+        Ok(_) => vec![],
+        Err(SolveError::Parse(e)) => vec![e],
+        Err(e) => vec![ParseError::new(e.to_string())],
+    }
+}
+
+/// The checks that apply to any day's input.
+pub fn common(data: &str) -> Vec<ParseError> {
+    let mut problems = line_endings(data);
+    problems.extend(trailing_whitespace(data));
+    problems
+}
+
+/// A byte order mark, CRLF line endings, or a missing final newline, which suggests the input
+/// was cut short.
+pub fn line_endings(data: &str) -> Vec<ParseError> {
+    let mut problems = vec![];
+    if data.trim().is_empty() {
+        problems.push(ParseError::new("input is empty"));
+        return problems;
+    }
+    if data.starts_with('\u{feff}') {
+        problems.push(ParseError::at(
+            data,
+            data,
+            "input starts with a byte order mark",
+        ));
+    }
+
+    // `lines` would strip the CR from each line
+    let lines = data.split_terminator('\n').collect::<Vec<&str>>();
+    let crlf = lines.iter().filter(|line| line.ends_with('\r')).count();
+    if let Some(line) = lines.iter().find(|line| line.ends_with('\r')) {
+        problems.push(ParseError::at(
+            data,
+            &line[line.len() - 1..],
+            format!("{} of {} lines end with CRLF", crlf, lines.len()),
+        ));
+    }
+
+    if !data.ends_with('\n') {
+        let last = data.lines().last().unwrap_or(data);
+        problems.push(ParseError::at(
+            data,
+            &last[last.len()..],
+            "no newline at the end of the input, it may have been cut short",
+        ));
+    }
+    problems
+}
+
+/// Lines ending in spaces or tabs.
+pub fn trailing_whitespace(data: &str) -> Vec<ParseError> {
+    data.lines()
+        .filter_map(|line| {
+            let line = line.trim_end_matches('\r');
+            let trimmed = line.trim_end_matches([' ', '\t']);
+            (trimmed.len() < line.len())
+                .then(|| ParseError::at(data, &line[trimmed.len()..], "trailing whitespace"))
+        })
+        .collect()
+}
+
+/// Rows of a grid that aren't as wide as the first.
+pub fn row_widths(data: &str) -> Vec<ParseError> {
+    let mut rows = data.lines().map(|line| line.trim_end_matches('\r'));
+    let Some(width) = rows.next().map(|row| row.chars().count()) else {
+        return vec![];
+    };
+    rows.filter(|row| row.chars().count() != width)
+        .map(|row| {
+            ParseError::at(
+                data,
+                row,
+                format!(
+                    "row is {} wide, but the first row is {}",
+                    row.chars().count(),
+                    width
+                ),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(problems: &[ParseError]) -> Vec<usize> {
+        problems
+            .iter()
+            .map(|p| p.location.as_ref().map_or(0, |l| l.line))
+            .collect()
+    }
+
+    #[test]
+    fn it_finds_mangled_line_endings() {
+        assert!(line_endings("1\n2\n").is_empty());
+        assert_eq!(lines(&line_endings("1\r\n2\r\n3\n")), vec![1]);
+        assert_eq!(
+            line_endings("1\r\n2\r\n3\n")[0].message,
+            "2 of 3 lines end with CRLF"
+        );
+        assert_eq!(lines(&line_endings("1\n2")), vec![2]);
+        assert_eq!(lines(&line_endings("\u{feff}1\n")), vec![1]);
+        assert_eq!(line_endings("\n\n")[0].message, "input is empty");
+    }
+
+    #[test]
+    fn it_finds_trailing_whitespace_and_ragged_rows() {
+        let problems = trailing_whitespace("a\nb \nc\r\nd\t\r\n");
+        assert_eq!(lines(&problems), vec![2, 4]);
+        assert_eq!(problems[0].location.as_ref().unwrap().column, 2);
+
+        assert!(row_widths("abc\ndef\n").is_empty());
+        assert_eq!(lines(&row_widths("abc\nde\nghi\njklm\n")), vec![2, 4]);
+    }
+}
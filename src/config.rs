@@ -0,0 +1,193 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::answers::{parse_key, parse_year};
+use crate::days::{self, Day, Params, Year};
+
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+/// Settings for the `aoc` runner, read from an `aoc.toml` in the current directory or the nearest
+/// one above it. Its relative paths are relative to the file, and anything left out has the same
+/// default as with no file at all:
+///
+/// ```toml
+/// data_dir = "data"
+/// profile = "alice"
+/// format = "text"
+/// cache_dir = ".aoc-cache"
+/// session = "53616c7465645f5f..."
+///
+/// [bench]
+/// iterations = 100
+/// warmup = 3
+///
+/// [params.2022.day15]
+/// row = 2000000
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Where inputs are kept, see `InputStore`.
+    pub data_dir: PathBuf,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile: Option<String>,
+    /// How results are printed: text, json or csv.
+    pub format: String,
+    /// Where answers are cached, see `Cache`.
+    pub cache_dir: PathBuf,
+    /// The adventofcode.com session cookie used to fetch inputs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session: Option<String>,
+    pub bench: BenchConfig,
+    #[serde(skip_serializing_if = "DayParams::is_empty")]
+    params: DayParams,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct BenchConfig {
+    /// Number of timed iterations.
+    pub iterations: usize,
+    /// Number of untimed iterations to run first.
+    pub warmup: usize,
+}
+
+// Parameters for each day's input, as a table of values per year and day.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct DayParams(BTreeMap<(Year, Day), Params>);
+
+type ParamsToml = BTreeMap<String, BTreeMap<String, BTreeMap<String, i64>>>;
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            data_dir: PathBuf::from("data"),
+            profile: None,
+            format: "text".to_string(),
+            cache_dir: PathBuf::from(".aoc-cache"),
+            session: None,
+            bench: BenchConfig::default(),
+            params: DayParams::default(),
+        }
+    }
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            iterations: 100,
+            warmup: 3,
+        }
+    }
+}
+
+impl Config {
+    /// The `aoc.toml` in `dir`, or the nearest directory above it that has one.
+    pub fn find(dir: &Path) -> Option<PathBuf> {
+        dir.ancestors()
+            .map(|dir| dir.join(CONFIG_FILE))
+            .find(|path| path.is_file())
+    }
+
+    /// Loads `path`, making its relative paths relative to the directory it's in.
+    pub fn load(path: &Path) -> Result<Self, ConfigError> {
+        let mut config = Self::from_toml(&fs::read_to_string(path)?)?;
+        let dir = path.parent().unwrap_or(Path::new(""));
+        config.data_dir = dir.join(&config.data_dir);
+        config.cache_dir = dir.join(&config.cache_dir);
+        Ok(config)
+    }
+
+    pub fn from_toml(s: &str) -> Result<Self, ConfigError> {
+        toml::from_str(s).map_err(|e| ConfigError::Format(e.to_string()))
+    }
+
+    pub fn to_toml(&self) -> Result<String, ConfigError> {
+        toml::to_string(self).map_err(|e| ConfigError::Format(e.to_string()))
+    }
+
+    /// The parameters given for `day`'s input, if any.
+    pub fn params(&self, year: Year, day: Day) -> Params {
+        self.params.0.get(&(year, day)).cloned().unwrap_or_default()
+    }
+}
+
+impl DayParams {
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl Serialize for DayParams {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut tables = ParamsToml::new();
+        for ((year, day), params) in &self.0 {
+            tables.entry(year.to_string()).or_default().insert(
+                format!("day{:02}", day),
+                params
+                    .iter()
+                    .map(|(name, value)| (name.to_string(), value))
+                    .collect(),
+            );
+        }
+        tables.serialize(serializer)
+    }
+}
+
+// Each day must be implemented, and declare the parameters given for it in one of its parts.
+impl<'de> Deserialize<'de> for DayParams {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut day_params = BTreeMap::new();
+        for (year_key, days) in ParamsToml::deserialize(deserializer)? {
+            let year = parse_year(&year_key).map_err(de::Error::custom)?;
+            for (day_key, values) in days {
+                let day = parse_key(&day_key, "day").map_err(de::Error::custom)?;
+                let solver = days::solver(year, day).ok_or_else(|| {
+                    de::Error::custom(format!("{} day {} is not implemented", year, day))
+                })?;
+                for name in values.keys() {
+                    let declared = solver
+                        .parts()
+                        .iter()
+                        .any(|&part| solver.params(part).iter().any(|param| param.name == name));
+                    if !declared {
+                        return Err(de::Error::custom(format!(
+                            "{} day {} has no parameter {:?}",
+                            year, day, name
+                        )));
+                    }
+                }
+                day_params.insert((year, day), values.into_iter().collect());
+            }
+        }
+        Ok(DayParams(day_params))
+    }
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Io(io::Error),
+    Format(String),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConfigError::Io(e) => write!(f, "couldn't read {}: {}", CONFIG_FILE, e),
+            ConfigError::Format(msg) => write!(f, "invalid {}: {}", CONFIG_FILE, msg),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl From<io::Error> for ConfigError {
+    fn from(e: io::Error) -> Self {
+        ConfigError::Io(e)
+    }
+}
@@ -0,0 +1,41 @@
+use std::iter;
+
+use crate::days::{Params, Part, Solver};
+use crate::runner::{self, Outcome, Status};
+
+/// How a part went when solved one way, `None` being the usual way rather than a variant.
+#[derive(Debug)]
+pub struct VariantRun {
+    pub variant: Option<&'static str>,
+    pub outcome: Outcome,
+}
+
+/// Solves `part` of `data` the usual way, then with each of its variants in turn, so their
+/// answers can be compared and their times are alike.
+pub fn crosscheck(solver: &dyn Solver, part: Part, data: &str, params: &Params) -> Vec<VariantRun> {
+    let (year, day) = (solver.year(), solver.day());
+    let params = params.only(solver.params(part));
+    iter::once(None)
+        .chain(solver.variants(part).iter().map(|v| Some(v.name)))
+        .map(|variant| {
+            let params = match variant {
+                Some(name) => params.clone().with_variant(name),
+                None => params.clone(),
+            };
+            let outcome = runner::run_data(year, day, part, data.to_string(), &params);
+            VariantRun { variant, outcome }
+        })
+        .collect()
+}
+
+/// Whether every way of solving the part solved it, with the same answer.
+pub fn agree(runs: &[VariantRun]) -> bool {
+    let mut answers = runs.iter().map(|run| match &run.outcome.status {
+        Status::Solved(answer) => Some(answer),
+        _ => None,
+    });
+    match answers.next() {
+        Some(Some(first)) => answers.all(|answer| answer == Some(first)),
+        _ => false,
+    }
+}
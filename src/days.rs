@@ -1,57 +1,297 @@
-pub mod day01;
-pub mod day02;
-pub mod day03;
-pub mod day04;
-pub mod day05;
-pub mod day06;
-pub mod day07;
-pub mod day08;
-pub mod day09;
-pub mod day10;
-pub mod day11;
-pub mod day12;
-pub mod day13;
-pub mod day14;
-pub mod day15;
-pub mod day16;
+use std::any::Any;
+use std::collections::BTreeMap;
 
+pub use crate::answer::Answer;
+use crate::check;
+use crate::error::{ParseError, SolveError};
+use crate::normalize_input;
+
+pub type Year = u16;
 pub type Day = u8;
 pub type Part = u8;
 
-pub fn run_day_part(day: Day, part: Part, data: String) -> String {
-    match (day, part) {
-        (1, 1) => day01::part1(data),
-        (1, 2) => day01::part2(data),
-        (2, 1) => day02::part1(data),
-        (2, 2) => day02::part2(data),
-        (3, 1) => day03::part1(data),
-        (3, 2) => day03::part2(data),
-        (4, 1) => day04::part1(data),
-        (4, 2) => day04::part2(data),
-        (5, 1) => day05::part1(data),
-        (5, 2) => day05::part2(data),
-        (6, 1) => day06::part1(data),
-        (6, 2) => day06::part2(data),
-        (7, 1) => day07::part1(data),
-        (7, 2) => day07::part2(data),
-        (8, 1) => day08::part1(data),
-        (8, 2) => day08::part2(data),
-        (9, 1) => day09::part1(data),
-        (9, 2) => day09::part2(data),
-        (10, 1) => day10::part1(data),
-        (10, 2) => day10::part2(data),
-        (11, 1) => day11::part1(data),
-        (11, 2) => day11::part2(data),
-        (12, 1) => day12::part1(data),
-        (12, 2) => day12::part2(data),
-        (13, 1) => day13::part1(data),
-        (13, 2) => day13::part2(data),
-        (14, 1) => day14::part1(data),
-        (14, 2) => day14::part2(data),
-        (15, 1) => day15::part1(data, None),
-        (15, 2) => day15::part2(data, None),
-        (16, 1) => day16::part1(data),
-        (16, 2) => day16::part2(data),
-        (day_m, part_m) => panic!("Day {}, part {} is not implemented", day_m, part_m),
+/// A named puzzle constant that can be overridden, e.g. the row day 15 looks at.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Param {
+    pub name: &'static str,
+    pub default: i64,
+    pub help: &'static str,
+}
+
+/// Another way of solving a part, e.g. with a different algorithm, which should give the same
+/// answer as the usual one.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Variant {
+    pub name: &'static str,
+    pub help: &'static str,
+}
+
+/// Parameter values by name, and the variant to solve with, if not the usual way.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Params {
+    values: BTreeMap<String, i64>,
+    variant: Option<String>,
+}
+
+impl Params {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, name: impl Into<String>, value: i64) -> Self {
+        self.values.insert(name.into(), value);
+        self
+    }
+
+    pub fn with_variant(mut self, name: impl Into<String>) -> Self {
+        self.variant = Some(name.into());
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty() && self.variant.is_none()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, i64)> {
+        self.values
+            .iter()
+            .map(|(name, &value)| (name.as_str(), value))
+    }
+
+    pub fn variant(&self) -> Option<&str> {
+        self.variant.as_deref()
+    }
+
+    /// Just the params that are `declared`, e.g. by one part of a day.
+    pub fn only(&self, declared: &[Param]) -> Params {
+        Params {
+            values: self
+                .values
+                .iter()
+                .filter(|(name, _)| declared.iter().any(|p| p.name == *name))
+                .map(|(name, &value)| (name.clone(), value))
+                .collect(),
+            variant: self.variant.clone(),
+        }
+    }
+
+    /// Just the params and variant that `solver`'s `part` declares.
+    pub fn for_part(&self, solver: &dyn Solver, part: Part) -> Params {
+        let mut params = self.only(solver.params(part));
+        params.variant = params
+            .variant
+            .filter(|name| solver.variants(part).iter().any(|v| v.name == name));
+        params
+    }
+
+    /// Looks up a parameter, which must fit in a `T`.
+    pub fn get<T: TryFrom<i64>>(&self, name: &str) -> Result<T, SolveError> {
+        let value = *self
+            .values
+            .get(name)
+            .ok_or_else(|| SolveError::InvalidParam(format!("no parameter named {:?}", name)))?;
+        T::try_from(value).map_err(|_| {
+            SolveError::InvalidParam(format!("{} is out of range for {:?}", value, name))
+        })
+    }
+
+    // These params with defaults filled in for any that are `declared` but not given, rejecting
+    // any that aren't declared, as well as a variant that isn't.
+    fn resolve(&self, declared: &[Param], variants: &[Variant]) -> Result<Params, SolveError> {
+        if let Some(name) = self
+            .variant()
+            .filter(|name| !variants.iter().any(|v| v.name == *name))
+        {
+            return Err(SolveError::InvalidVariant(format!(
+                "unknown variant {:?}",
+                name
+            )));
+        }
+        if let Some(name) = self
+            .values
+            .keys()
+            .find(|name| !declared.iter().any(|p| p.name == *name))
+        {
+            return Err(SolveError::InvalidParam(format!(
+                "unknown parameter {:?}",
+                name
+            )));
+        }
+
+        let mut resolved = self.clone();
+        for param in declared {
+            resolved
+                .values
+                .entry(param.name.to_string())
+                .or_insert(param.default);
+        }
+        Ok(resolved)
+    }
+}
+
+impl FromIterator<(String, i64)> for Params {
+    fn from_iter<I: IntoIterator<Item = (String, i64)>>(iter: I) -> Self {
+        Self {
+            values: iter.into_iter().collect(),
+            variant: None,
+        }
+    }
+}
+
+/// A day's puzzle, with parsing split out from solving so the two can be run (and timed) apart.
+pub trait Puzzle: Sync {
+    type Input: 'static;
+
+    fn year(&self) -> Year;
+    fn day(&self) -> Day;
+    fn title(&self) -> &'static str;
+    fn example(&self) -> &'static str;
+
+    fn parts(&self) -> &'static [Part] {
+        &[1, 2]
+    }
+
+    /// Parameters `part` can be run with, which `solve` is always given values for.
+    fn params(&self, _part: Part) -> &'static [Param] {
+        &[]
+    }
+
+    /// Other ways `part` can be solved, which `solve` runs instead of the usual way when `params`
+    /// has one of them as its variant.
+    fn variants(&self, _part: Part) -> &'static [Variant] {
+        &[]
+    }
+
+    /// Problems with `data` that `parse` puts up with, or only reports the first of, for
+    /// `aoc check`. Days with structural rules of their own add them to the `check::common` ones.
+    fn check(&self, data: &str) -> Vec<ParseError> {
+        check::common(data)
+    }
+
+    fn parse(&self, data: &str) -> Result<Self::Input, SolveError>;
+    fn solve(&self, part: Part, input: &Self::Input, params: &Params)
+        -> Result<Answer, SolveError>;
+}
+
+/// Parsed input for a `Solver`, only usable with the solver that produced it.
+pub type Parsed = Box<dyn Any>;
+
+/// Object safe view of a `Puzzle`, so days with different input types can share a registry.
+pub trait Solver: Sync {
+    fn year(&self) -> Year;
+    fn day(&self) -> Day;
+    fn title(&self) -> &'static str;
+    fn example(&self) -> &'static str;
+    fn parts(&self) -> &'static [Part];
+    fn params(&self, part: Part) -> &'static [Param];
+    fn variants(&self, part: Part) -> &'static [Variant];
+
+    fn check(&self, data: &str) -> Vec<ParseError>;
+    fn parse(&self, data: &str) -> Result<Parsed, SolveError>;
+    fn solve_parsed(
+        &self,
+        part: Part,
+        input: &Parsed,
+        params: &Params,
+    ) -> Result<Answer, SolveError>;
+
+    fn solve(&self, part: Part, data: String, params: &Params) -> Result<Answer, SolveError> {
+        let input = self.parse(&data)?;
+        self.solve_parsed(part, &input, params)
+    }
+}
+
+impl<P: Puzzle> Solver for P {
+    fn year(&self) -> Year {
+        Puzzle::year(self)
+    }
+
+    fn day(&self) -> Day {
+        Puzzle::day(self)
+    }
+
+    fn title(&self) -> &'static str {
+        Puzzle::title(self)
+    }
+
+    fn example(&self) -> &'static str {
+        Puzzle::example(self)
+    }
+
+    fn parts(&self) -> &'static [Part] {
+        Puzzle::parts(self)
+    }
+
+    fn params(&self, part: Part) -> &'static [Param] {
+        Puzzle::params(self, part)
+    }
+
+    fn variants(&self, part: Part) -> &'static [Variant] {
+        Puzzle::variants(self, part)
+    }
+
+    fn check(&self, data: &str) -> Vec<ParseError> {
+        Puzzle::check(self, data)
+    }
+
+    fn parse(&self, data: &str) -> Result<Parsed, SolveError> {
+        Ok(Box::new(Puzzle::parse(self, &normalize_input(data))?))
+    }
+
+    fn solve_parsed(
+        &self,
+        part: Part,
+        input: &Parsed,
+        params: &Params,
+    ) -> Result<Answer, SolveError> {
+        let params = params.resolve(Puzzle::params(self, part), Puzzle::variants(self, part))?;
+        let input = input.downcast_ref::<P::Input>().ok_or_else(|| {
+            SolveError::Unsolvable(format!(
+                "input wasn't parsed by the {} day {} solver",
+                Puzzle::year(self),
+                Puzzle::day(self)
+            ))
+        })?;
+        Puzzle::solve(self, part, input, &params)
+    }
+}
+
+// Each year's solvers, in year order.
+static YEARS: &[&[&dyn Solver]] = &[crate::year2022::SOLVERS];
+
+/// Every solver, in year then day order.
+pub fn solvers() -> impl Iterator<Item = &'static dyn Solver> {
+    YEARS.iter().flat_map(|solvers| solvers.iter().copied())
+}
+
+/// The years with at least one solver, in order.
+pub fn years() -> Vec<Year> {
+    let mut years = solvers().map(|s| s.year()).collect::<Vec<Year>>();
+    years.dedup();
+    years
+}
+
+/// The most recent year with a solver, which is used when a year isn't given.
+pub fn latest_year() -> Year {
+    years()
+        .last()
+        .copied()
+        .expect("at least one year is registered")
+}
+
+pub fn solver(year: Year, day: Day) -> Option<&'static dyn Solver> {
+    solvers().find(|s| s.year() == year && s.day() == day)
+}
+
+pub fn run_day_part(
+    year: Year,
+    day: Day,
+    part: Part,
+    data: String,
+    params: &Params,
+) -> Result<Answer, SolveError> {
+    match solver(year, day) {
+        Some(s) if s.parts().contains(&part) => s.solve(part, data, params),
+        _ => Err(SolveError::NotImplemented { year, day, part }),
     }
 }
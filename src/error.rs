@@ -0,0 +1,145 @@
+use std::fmt;
+use std::io;
+
+use crate::days::{Day, Part, Year};
+
+#[derive(Debug)]
+pub enum SolveError {
+    Io(io::Error),
+    Parse(ParseError),
+    Unsolvable(String),
+    InvalidParam(String),
+    InvalidVariant(String),
+    NotImplemented { year: Year, day: Day, part: Part },
+}
+
+impl SolveError {
+    pub fn parse(msg: impl Into<String>) -> Self {
+        SolveError::Parse(ParseError::new(msg))
+    }
+
+    pub fn unsolvable(msg: impl Into<String>) -> Self {
+        SolveError::Unsolvable(msg.into())
+    }
+
+    /// Points a parse error without a location at the start of `at`, a slice of `source`.
+    pub fn located(self, source: &str, at: &str) -> Self {
+        match self {
+            SolveError::Parse(ParseError {
+                message,
+                location: None,
+            }) => SolveError::Parse(ParseError::at(source, at, message)),
+            e => e,
+        }
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SolveError::Io(e) => write!(f, "couldn't read input: {}", e),
+            SolveError::Parse(e) => write!(f, "couldn't parse input: {}", e),
+            SolveError::Unsolvable(msg) => write!(f, "couldn't solve input: {}", msg),
+            SolveError::InvalidParam(msg) => write!(f, "invalid parameter: {}", msg),
+            SolveError::InvalidVariant(msg) => write!(f, "invalid variant: {}", msg),
+            SolveError::NotImplemented { year, day, part } => {
+                write!(f, "{} day {}, part {} is not implemented", year, day, part)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SolveError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SolveError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for SolveError {
+    fn from(e: io::Error) -> Self {
+        SolveError::Io(e)
+    }
+}
+
+impl From<ParseError> for SolveError {
+    fn from(e: ParseError) -> Self {
+        SolveError::Parse(e)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ParseError {
+    pub message: String,
+    pub location: Option<Location>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+    pub line_text: String,
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            location: None,
+        }
+    }
+
+    /// An error at the start of `at`, which must be a slice of `source`.
+    pub fn at(source: &str, at: &str, message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            location: Location::find(source, at),
+        }
+    }
+}
+
+impl Location {
+    fn find(source: &str, at: &str) -> Option<Self> {
+        let offset = (at.as_ptr() as usize).checked_sub(source.as_ptr() as usize)?;
+        if offset > source.len() {
+            return None;
+        }
+
+        let before = &source[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let line_end = source[offset..]
+            .find('\n')
+            .map_or(source.len(), |i| offset + i);
+
+        Some(Self {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            line_text: source[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+        })
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.location {
+            None => write!(f, "{}", self.message),
+            Some(loc) => {
+                let gutter = " ".repeat(loc.line.to_string().len());
+                writeln!(
+                    f,
+                    "line {}, column {}: {}",
+                    loc.line, loc.column, self.message
+                )?;
+                writeln!(f, "{} |", gutter)?;
+                writeln!(f, "{} | {}", loc.line, loc.line_text)?;
+                write!(f, "{} | {}^", gutter, " ".repeat(loc.column - 1))
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
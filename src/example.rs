@@ -0,0 +1,154 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::answers::{check_expected, Check};
+use crate::days::{self, Day, Params, Part, Year};
+use crate::runner::{self, Outcome};
+
+/// An example input for a day, read from `<dir>/YYYY/dayNN/<name>.txt`, along with the answers it
+/// should give from `<dir>/YYYY/dayNN/expected.toml`:
+///
+/// ```toml
+/// [example]
+/// part1 = "26"
+/// part2 = "56000011"
+///
+/// [example.params]
+/// row = 10
+/// ```
+///
+/// Params apply to whichever parts declare them.
+#[derive(Debug)]
+pub struct Example {
+    pub year: Year,
+    pub day: Day,
+    pub name: String,
+    pub path: PathBuf,
+    pub expected: BTreeMap<Part, String>,
+    pub params: Params,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Expected {
+    part1: Option<String>,
+    part2: Option<String>,
+    #[serde(default)]
+    params: BTreeMap<String, i64>,
+}
+
+const EXPECTED_FILE: &str = "expected.toml";
+
+impl Example {
+    /// Runs `part` against this example, with its params.
+    pub fn run(&self, part: Part) -> Outcome {
+        let declared = days::solver(self.year, self.day).map_or(&[][..], |s| s.params(part));
+        runner::run_part(
+            self.year,
+            self.day,
+            part,
+            &self.path,
+            &self.params.only(declared),
+        )
+    }
+
+    pub fn check(&self, outcome: &Outcome) -> Check {
+        check_expected(
+            self.expected.get(&outcome.part).map(String::as_str),
+            outcome,
+        )
+    }
+}
+
+/// Every example for every registered day, in year, day then name order.
+pub fn all_examples(dir: &Path) -> Result<Vec<Example>, ExampleError> {
+    let mut examples = vec![];
+    for solver in days::solvers() {
+        examples.extend(day_examples(dir, solver.year(), solver.day())?);
+    }
+    Ok(examples)
+}
+
+/// The examples for `day`, in name order. A day without an examples directory has none.
+pub fn day_examples(dir: &Path, year: Year, day: Day) -> Result<Vec<Example>, ExampleError> {
+    let day_dir = dir.join(year.to_string()).join(format!("day{:02}", day));
+    let entries = match fs::read_dir(&day_dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut inputs = BTreeMap::new();
+    for entry in entries {
+        let path = entry?.path();
+        if path.extension().is_some_and(|ext| ext == "txt") {
+            let name = path.file_stem().unwrap().to_string_lossy().into_owned();
+            inputs.insert(name, path);
+        }
+    }
+
+    let expected_path = day_dir.join(EXPECTED_FILE);
+    let mut expected = match fs::read_to_string(&expected_path) {
+        Ok(s) => toml::from_str::<BTreeMap<String, Expected>>(&s)
+            .map_err(|e| ExampleError::Format(expected_path.clone(), e.to_string()))?,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => BTreeMap::new(),
+        Err(e) => return Err(e.into()),
+    };
+    if let Some(name) = expected.keys().find(|name| !inputs.contains_key(*name)) {
+        return Err(ExampleError::Format(
+            expected_path,
+            format!("there's no {}.txt for [{}]", name, name),
+        ));
+    }
+
+    Ok(inputs
+        .into_iter()
+        .map(|(name, path)| {
+            let Expected {
+                part1,
+                part2,
+                params,
+            } = expected.remove(&name).unwrap_or_default();
+            let expected = [(1, part1), (2, part2)]
+                .into_iter()
+                .filter_map(|(part, answer)| Some((part, answer?.trim_end().to_string())))
+                .collect();
+            Example {
+                year,
+                day,
+                name,
+                path,
+                expected,
+                params: params.into_iter().collect(),
+            }
+        })
+        .collect())
+}
+
+#[derive(Debug)]
+pub enum ExampleError {
+    Io(io::Error),
+    Format(PathBuf, String),
+}
+
+impl fmt::Display for ExampleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ExampleError::Io(e) => write!(f, "couldn't read examples: {}", e),
+            ExampleError::Format(path, msg) => write!(f, "invalid {}: {}", path.display(), msg),
+        }
+    }
+}
+
+impl std::error::Error for ExampleError {}
+
+impl From<io::Error> for ExampleError {
+    fn from(e: io::Error) -> Self {
+        ExampleError::Io(e)
+    }
+}
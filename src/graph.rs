@@ -42,7 +42,10 @@ where
         }
     }
 
+    /// The length of the shortest path from `from` to each vertex it can reach. Vertices it can't
+    /// reach are left out.
     pub fn shortest_paths_from(&self, from: &T) -> HashMap<T, W> {
+        let _span = tracing::debug_span!("shortest_paths_from", ?from).entered();
         let mut dist: HashMap<T, W> = HashMap::new();
         let mut queue: DoublePriorityQueue<T, W> = DoublePriorityQueue::new();
 
@@ -56,13 +59,22 @@ where
             queue.push(*v, v_dist.clone());
         }
 
-        while let Some((u, _priority)) = queue.pop_min() {
+        while let Some((u, priority)) = queue.pop_min() {
+            // the rest are still at the sentinel, so can't be reached
+            if priority == W::max_value() {
+                dist.remove(&u);
+                for (v, _) in queue.into_iter() {
+                    dist.remove(&v);
+                }
+                break;
+            }
             if let Some(edges) = self.edges.get(&u) {
                 let dist_u = dist.get(&u).unwrap().clone();
                 for (v, weight) in edges.iter() {
                     let dist_v = dist.get(&v).unwrap();
                     let alt = dist_u.clone() + weight.clone();
                     if alt < *dist_v {
+                        tracing::trace!(?u, ?v, "shorter path found");
                         dist.insert(v.clone(), alt);
                         queue.change_priority(v, alt);
                     }
@@ -70,6 +82,29 @@ where
             }
         }
 
+        tracing::debug!(vertices = dist.len(), "shortest paths found");
         dist
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unreachable_vertices_are_left_out() {
+        let mut graph: Graph<char, u32> = Graph::new();
+        for v in ['a', 'b', 'c', 'd'] {
+            graph.push_vertex(v);
+        }
+        graph.push_edge('a', 'b', 2);
+        graph.push_edge('c', 'd', 1);
+        graph.push_edge('d', 'a', 1);
+
+        let dist = graph.shortest_paths_from(&'a');
+        assert_eq!(dist.get(&'a'), Some(&0));
+        assert_eq!(dist.get(&'b'), Some(&2));
+        assert_eq!(dist.get(&'c'), None);
+        assert_eq!(dist.get(&'d'), None);
+    }
+}
@@ -0,0 +1,272 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::days::{Day, Year};
+use crate::runner::InputInfo;
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = concat!(
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION"),
+    " (input fetcher)"
+);
+
+/// Where puzzle inputs are kept: `<dir>/YYYY/dayNN.txt`, or `<dir>/<profile>/YYYY/dayNN.txt` to
+/// keep each person's inputs apart. Inputs that were fetched have their metadata alongside, in
+/// `dayNN.toml`. With a `Fetch`er, missing inputs can be fetched as they're needed.
+pub struct InputStore {
+    dir: PathBuf,
+    profile: Option<String>,
+    fetcher: Option<Box<dyn Fetch>>,
+}
+
+/// Where an input came from, and what it was when it was fetched.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InputMeta {
+    /// When it was fetched, in UTC, e.g. `2022-12-01T05:00:00Z`.
+    pub fetched: String,
+    pub sha256: String,
+    pub source: String,
+}
+
+/// Gets a day's puzzle input from somewhere, e.g. the Advent of Code site.
+pub trait Fetch: Sync {
+    fn fetch(&self, year: Year, day: Day) -> Result<String, InputError>;
+
+    /// Where `fetch` gets the input from, for its metadata.
+    fn source(&self, year: Year, day: Day) -> String;
+}
+
+impl InputStore {
+    pub fn new(dir: impl Into<PathBuf>, profile: Option<&str>) -> Result<Self, InputError> {
+        // profiles share the data directory with the year directories, so mustn't look like one
+        if let Some(name) = profile {
+            let valid = name.starts_with(|c: char| c.is_ascii_alphabetic())
+                && name
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+            if !valid {
+                return Err(InputError::Profile(format!(
+                    "{:?} isn't a valid profile name, use letters, digits, - and _, starting \
+                     with a letter",
+                    name
+                )));
+            }
+        }
+        Ok(Self {
+            dir: dir.into(),
+            profile: profile.map(String::from),
+            fetcher: None,
+        })
+    }
+
+    pub fn with_fetcher(mut self, fetcher: impl Fetch + 'static) -> Self {
+        self.fetcher = Some(Box::new(fetcher));
+        self
+    }
+
+    pub fn can_fetch(&self) -> bool {
+        self.fetcher.is_some()
+    }
+
+    pub fn path(&self, year: Year, day: Day) -> PathBuf {
+        self.year_dir(year).join(format!("day{:02}.txt", day))
+    }
+
+    pub fn metadata_path(&self, year: Year, day: Day) -> PathBuf {
+        self.year_dir(year).join(format!("day{:02}.toml", day))
+    }
+
+    fn year_dir(&self, year: Year) -> PathBuf {
+        let dir = match &self.profile {
+            Some(profile) => self.dir.join(profile),
+            None => self.dir.clone(),
+        };
+        dir.join(year.to_string())
+    }
+
+    /// The path to `day`'s input, fetching it first if it's missing and there's a fetcher.
+    pub fn ensure(&self, year: Year, day: Day) -> Result<PathBuf, InputError> {
+        let path = self.path(year, day);
+        match &self.fetcher {
+            Some(fetcher) if !path.exists() => {
+                self.save(year, day, fetcher.as_ref())?;
+                Ok(path)
+            }
+            _ => Ok(path),
+        }
+    }
+
+    /// Fetches `day`'s input, replacing any already stored.
+    pub fn fetch(&self, year: Year, day: Day) -> Result<PathBuf, InputError> {
+        let fetcher = self
+            .fetcher
+            .as_ref()
+            .ok_or_else(|| InputError::Fetch("no way to fetch inputs is configured".to_string()))?;
+        self.save(year, day, fetcher.as_ref())?;
+        Ok(self.path(year, day))
+    }
+
+    fn save(&self, year: Year, day: Day, fetcher: &dyn Fetch) -> Result<(), InputError> {
+        let data = fetcher.fetch(year, day)?;
+        let meta = InputMeta {
+            fetched: utc_timestamp(SystemTime::now()),
+            sha256: InputInfo::new(None, &data).sha256,
+            source: fetcher.source(year, day),
+        };
+        let meta = toml::to_string(&meta).map_err(|e| InputError::Metadata(e.to_string()))?;
+
+        fs::create_dir_all(self.year_dir(year))?;
+        fs::write(self.path(year, day), data)?;
+        fs::write(self.metadata_path(year, day), meta)?;
+        tracing::info!(year, day, path = %self.path(year, day).display(), "fetched input");
+        Ok(())
+    }
+
+    /// `day`'s metadata, if its input was fetched.
+    pub fn metadata(&self, year: Year, day: Day) -> Result<Option<InputMeta>, InputError> {
+        match fs::read_to_string(self.metadata_path(year, day)) {
+            Ok(s) => toml::from_str(&s)
+                .map(Some)
+                .map_err(|e| InputError::Metadata(e.to_string())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+/// Fetches inputs over HTTP, as the Advent of Code site serves them to a logged in user.
+pub struct HttpFetch {
+    base_url: String,
+    session: String,
+}
+
+impl HttpFetch {
+    /// Fetches from adventofcode.com with the `session` cookie of a logged in browser.
+    pub fn new(session: impl Into<String>) -> Self {
+        Self {
+            base_url: DEFAULT_BASE_URL.to_string(),
+            session: session.into(),
+        }
+    }
+
+    /// Fetches from another server that serves inputs the same way, e.g. one for testing.
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+}
+
+impl Fetch for HttpFetch {
+    fn fetch(&self, year: Year, day: Day) -> Result<String, InputError> {
+        let url = self.source(year, day);
+        let response = ureq::get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", USER_AGENT)
+            .call();
+        match response {
+            Ok(response) => Ok(response.into_string()?),
+            Err(ureq::Error::Status(status, _)) => Err(InputError::Fetch(match status {
+                400 | 401 => format!("{} refused the session token ({})", url, status),
+                404 => format!("{} isn't available yet ({})", url, status),
+                _ => format!("{} responded with status {}", url, status),
+            })),
+            Err(e) => Err(InputError::Fetch(e.to_string())),
+        }
+    }
+
+    fn source(&self, year: Year, day: Day) -> String {
+        format!("{}/{}/day/{}/input", self.base_url, year, day)
+    }
+}
+
+// Formats `time` as an RFC 3339 timestamp in UTC, to the second.
+fn utc_timestamp(time: SystemTime) -> String {
+    let secs = time.duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+    let (days, secs) = (secs / 86400, secs % 86400);
+
+    // days since 1970-01-01 to a date, per Howard Hinnant's `civil_from_days`
+    let z = days as i64 + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        secs / 3600,
+        secs % 3600 / 60,
+        secs % 60
+    )
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    Io(io::Error),
+    Profile(String),
+    Fetch(String),
+    Metadata(String),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputError::Io(e) => write!(f, "couldn't access input: {}", e),
+            InputError::Profile(msg) => write!(f, "{}", msg),
+            InputError::Fetch(msg) => write!(f, "couldn't fetch input: {}", msg),
+            InputError::Metadata(msg) => write!(f, "invalid input metadata: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+impl From<io::Error> for InputError {
+    fn from(e: io::Error) -> Self {
+        InputError::Io(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn it_formats_utc_timestamps() {
+        let at = |secs| utc_timestamp(UNIX_EPOCH + Duration::from_secs(secs));
+        assert_eq!(at(0), "1970-01-01T00:00:00Z");
+        assert_eq!(at(1669870800), "2022-12-01T05:00:00Z");
+        assert_eq!(at(951782400), "2000-02-29T00:00:00Z");
+    }
+
+    #[test]
+    fn profiles_get_their_own_directory() {
+        let store = InputStore::new("data", None).unwrap();
+        assert_eq!(store.path(2022, 1), PathBuf::from("data/2022/day01.txt"));
+        let store = InputStore::new("data", Some("alice")).unwrap();
+        assert_eq!(
+            store.path(2022, 1),
+            PathBuf::from("data/alice/2022/day01.txt")
+        );
+        assert_eq!(
+            store.metadata_path(2022, 1),
+            PathBuf::from("data/alice/2022/day01.toml")
+        );
+        assert!(InputStore::new("data", Some("2022")).is_err());
+        assert!(InputStore::new("data", Some("../bob")).is_err());
+    }
+}
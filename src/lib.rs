@@ -1,22 +1,81 @@
+pub mod answer;
+pub mod answers;
+pub mod attempts;
+pub mod batch;
+pub mod bench;
+pub mod cache;
+pub mod check;
+pub mod config;
 pub mod coord;
+pub mod crosscheck;
 pub mod days;
+pub mod error;
+pub mod example;
 pub mod graph;
+pub mod inputs;
+pub mod memory;
+pub mod parser;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod watch;
+pub mod year2022;
 
+use std::borrow::Cow;
 use std::io::Read;
 use std::path::PathBuf;
 
 use std::fs::File;
 
 use crate::days::*;
+use crate::error::SolveError;
 
-pub fn run(day: Day, part: Part, path: PathBuf) -> std::io::Result<String> {
-    let data = load_data(path)?;
-    Ok(days::run_day_part(day, part, data))
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOC: memory::CountingAlloc = memory::CountingAlloc;
+
+pub fn run(
+    year: Year,
+    day: Day,
+    part: Part,
+    path: PathBuf,
+    params: &Params,
+) -> Result<Answer, SolveError> {
+    run_day_part(year, day, part, load_data(path)?, params)
+}
+
+/// As `run`, but reads the input from `reader`, e.g. stdin or a decompressor.
+pub fn run_reader(
+    year: Year,
+    day: Day,
+    part: Part,
+    reader: impl Read,
+    params: &Params,
+) -> Result<Answer, SolveError> {
+    run_day_part(year, day, part, read_data(reader)?, params)
+}
+
+/// Puts input in the form every day's parser expects, whichever editor it was saved with: without
+/// a byte order mark, with `\n` line endings, and ending in exactly one newline. Solvers apply this
+/// before parsing.
+pub fn normalize_input(data: &str) -> Cow<'_, str> {
+    let data = data.strip_prefix('\u{feff}').unwrap_or(data);
+    let body = data.trim_end_matches(['\r', '\n']);
+    if body.is_empty() {
+        Cow::Borrowed("")
+    } else if !body.contains('\r') && &data[body.len()..] == "\n" {
+        Cow::Borrowed(data)
+    } else {
+        Cow::Owned(body.replace("\r\n", "\n") + "\n")
+    }
+}
+
+pub fn load_data(file_path: PathBuf) -> Result<String, std::io::Error> {
+    read_data(File::open(file_path)?)
 }
 
-fn load_data(file_path: PathBuf) -> Result<String, std::io::Error> {
-    let mut file = File::open(file_path)?;
+pub fn read_data(mut reader: impl Read) -> Result<String, std::io::Error> {
     let mut data = String::new();
-    file.read_to_string(&mut data)?;
+    reader.read_to_string(&mut data)?;
     Ok(data)
 }
@@ -0,0 +1,166 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use serde::Serialize;
+
+/// Memory allocated by a stretch of work on one thread.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct AllocStats {
+    pub allocations: u64,
+    pub bytes: u64,
+    /// The most memory the work held at once, above what was live when it started.
+    pub peak_bytes: u64,
+}
+
+/// Wraps the system allocator, counting each thread's allocations so they can be `measure`d.
+/// Installed as the global allocator by the `alloc-stats` feature.
+pub struct CountingAlloc;
+
+#[derive(Clone, Copy)]
+struct Counts {
+    allocations: u64,
+    bytes: u64,
+    // relative to the start of the measurement, so memory allocated before it and freed during it
+    // takes this below zero
+    live: i64,
+    peak: i64,
+}
+
+impl Counts {
+    const ZERO: Counts = Counts {
+        allocations: 0,
+        bytes: 0,
+        live: 0,
+        peak: 0,
+    };
+}
+
+thread_local! {
+    // const initialised and without a destructor, so it's safe to use from the allocator
+    static COUNTS: Cell<Counts> = const { Cell::new(Counts::ZERO) };
+}
+
+fn record(allocated: usize, freed: usize) {
+    let _ = COUNTS.try_with(|counts| {
+        let mut c = counts.get();
+        if allocated > 0 {
+            c.allocations += 1;
+            c.bytes += allocated as u64;
+        }
+        c.live += allocated as i64 - freed as i64;
+        c.peak = c.peak.max(c.live);
+        counts.set(c);
+    });
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record(0, layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record(new_size, layout.size());
+        }
+        new_ptr
+    }
+}
+
+/// Whether the counting allocator is installed, so `measure` sees every allocation.
+pub fn enabled() -> bool {
+    cfg!(feature = "alloc-stats")
+}
+
+/// Runs `f`, counting what it allocates on this thread through `CountingAlloc`. Measurements can
+/// be nested.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, AllocStats) {
+    let outer = COUNTS.with(|counts| counts.replace(Counts::ZERO));
+    let result = f();
+    let inner = COUNTS.with(|counts| {
+        let inner = counts.get();
+        // the work done inside counts towards any measurement around this one
+        counts.set(Counts {
+            allocations: outer.allocations + inner.allocations,
+            bytes: outer.bytes + inner.bytes,
+            live: outer.live + inner.live,
+            peak: outer.peak.max(outer.live + inner.peak),
+        });
+        inner
+    });
+
+    let stats = AllocStats {
+        allocations: inner.allocations,
+        bytes: inner.bytes,
+        peak_bytes: inner.peak.max(0) as u64,
+    };
+    (result, stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn alloc(size: usize) -> (*mut u8, Layout) {
+        let layout = Layout::array::<u8>(size).unwrap();
+        (unsafe { CountingAlloc.alloc(layout) }, layout)
+    }
+
+    fn free((ptr, layout): (*mut u8, Layout)) {
+        unsafe { CountingAlloc.dealloc(ptr, layout) }
+    }
+
+    #[test]
+    fn it_counts_allocations_and_peak() {
+        let before = alloc(64);
+        let (_, stats) = measure(|| {
+            let a = alloc(100);
+            let b = alloc(50);
+            free(a);
+            free(b);
+            let c = alloc(120);
+            free(c);
+            free(before);
+        });
+        assert_eq!(
+            stats,
+            AllocStats {
+                allocations: 3,
+                bytes: 270,
+                peak_bytes: 150
+            }
+        );
+    }
+
+    #[test]
+    fn it_nests_measurements() {
+        let (inner, outer) = measure(|| {
+            let a = alloc(10);
+            let (_, inner) = measure(|| free(alloc(30)));
+            free(a);
+            inner
+        });
+        assert_eq!(inner.allocations, 1);
+        assert_eq!(inner.peak_bytes, 30);
+        assert_eq!(outer.allocations, 2);
+        assert_eq!(outer.bytes, 40);
+        assert_eq!(outer.peak_bytes, 40);
+    }
+}
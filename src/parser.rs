@@ -0,0 +1,73 @@
+use nom::{
+    combinator::all_consuming,
+    error::{ErrorKind, VerboseError, VerboseErrorKind},
+    IResult, Parser,
+};
+
+use crate::error::ParseError;
+
+pub type ParseResult<'a, O> = IResult<&'a str, O, VerboseError<&'a str>>;
+
+// Runs `parser` over the whole of `input`, allowing only trailing newlines to be left over.
+pub fn parse_all<'a, O, P>(input: &'a str, parser: P) -> Result<O, ParseError>
+where
+    P: Parser<&'a str, O, VerboseError<&'a str>>,
+{
+    parse_all_in(input, input, parser)
+}
+
+// As `parse_all`, but for parsing a slice of a larger `source` (e.g. a single line), so errors are
+// reported at their position in the full source.
+pub fn parse_all_in<'a, O, P>(source: &'a str, input: &'a str, parser: P) -> Result<O, ParseError>
+where
+    P: Parser<&'a str, O, VerboseError<&'a str>>,
+{
+    let input = input.trim_end_matches(&['\n', '\r'][..]);
+    match all_consuming(parser).parse(input) {
+        Ok((_rest, output)) => Ok(output),
+        Err(nom::Err::Error(e)) | Err(nom::Err::Failure(e)) => Err(to_parse_error(source, e)),
+        Err(nom::Err::Incomplete(_)) => Err(ParseError::new("unexpected end of input")),
+    }
+}
+
+fn to_parse_error<'a>(source: &'a str, e: VerboseError<&'a str>) -> ParseError {
+    let (at, kind) = match e.errors.first() {
+        Some(err) => err,
+        None => return ParseError::new("invalid input"),
+    };
+
+    let message = match kind {
+        VerboseErrorKind::Char(c) => format!("expected {:?}", c),
+        VerboseErrorKind::Context(ctx) => format!("expected {}", ctx),
+        VerboseErrorKind::Nom(ErrorKind::Digit) => "expected a number".to_string(),
+        VerboseErrorKind::Nom(_) => unexpected(at),
+    };
+
+    // only the innermost context is reported, outer ones are rarely more helpful
+    let context = e.errors.iter().find_map(|(_, kind)| match kind {
+        VerboseErrorKind::Context(ctx) => Some(*ctx),
+        _ => None,
+    });
+
+    match context {
+        Some(ctx) if !matches!(kind, VerboseErrorKind::Context(_)) => {
+            ParseError::at(source, at, format!("{} in {}", message, ctx))
+        }
+        _ => ParseError::at(source, at, message),
+    }
+}
+
+fn unexpected(at: &str) -> String {
+    match at.chars().next() {
+        None => "unexpected end of input".to_string(),
+        Some('\n') => "unexpected line break".to_string(),
+        Some(c) if c.is_whitespace() => format!("unexpected {:?}", c),
+        Some(_) => {
+            let token = at.split_whitespace().next().unwrap_or(at);
+            format!(
+                "unexpected {:?}",
+                token.chars().take(20).collect::<String>()
+            )
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::days::{Answer, Day, Part, Year};
+use crate::runner::{Outcome, Status};
+
+/// A flat, machine-readable view of an `Outcome`.
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Record {
+    pub year: Year,
+    pub day: Day,
+    pub part: Part,
+    pub answer: Option<Answer>,
+    pub elapsed_ns: u128,
+    pub allocations: Option<u64>,
+    pub alloc_bytes: Option<u64>,
+    pub peak_bytes: Option<u64>,
+    pub input_path: Option<String>,
+    pub input_sha256: Option<String>,
+    pub status: &'static str,
+    /// Whether the answer came from the cache rather than being solved.
+    pub cached: bool,
+    pub error: Option<String>,
+}
+
+const CSV_HEADER: &str =
+    "year,day,part,answer,elapsed_ns,allocations,alloc_bytes,peak_bytes,input_path,input_sha256,status,cached,error";
+
+impl From<&Outcome> for Record {
+    fn from(outcome: &Outcome) -> Self {
+        let (answer, status, error) = match &outcome.status {
+            Status::Solved(answer) => (Some(answer.clone()), "ok", None),
+            Status::Failed(e) => (None, "failed", Some(e.to_string())),
+            Status::Panicked(msg) => (None, "panicked", Some(msg.clone())),
+            Status::MissingInput(_) => (None, "skipped", None),
+        };
+        let input_path = match (&outcome.status, &outcome.input) {
+            (Status::MissingInput(path), _) => Some(path.display().to_string()),
+            (_, Some(input)) => input.path.as_ref().map(|p| p.display().to_string()),
+            _ => None,
+        };
+
+        Self {
+            year: outcome.year,
+            day: outcome.day,
+            part: outcome.part,
+            answer,
+            elapsed_ns: outcome.elapsed.as_nanos(),
+            allocations: outcome.allocs.map(|a| a.allocations),
+            alloc_bytes: outcome.allocs.map(|a| a.bytes),
+            peak_bytes: outcome.allocs.map(|a| a.peak_bytes),
+            input_path,
+            input_sha256: outcome.input.as_ref().map(|i| i.sha256.clone()),
+            status,
+            cached: outcome.cached,
+            error,
+        }
+    }
+}
+
+impl Record {
+    /// One JSON object, on a single line.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("records serialize to JSON")
+    }
+
+    pub fn csv_header() -> &'static str {
+        CSV_HEADER
+    }
+
+    pub fn to_csv(&self) -> String {
+        let opt = |s: &Option<String>| csv_field(s.as_deref().unwrap_or(""));
+        let count = |n: Option<u64>| n.map_or_else(String::new, |n| n.to_string());
+        [
+            self.year.to_string(),
+            self.day.to_string(),
+            self.part.to_string(),
+            opt(&self.answer.as_ref().map(Answer::to_string)),
+            self.elapsed_ns.to_string(),
+            count(self.allocations),
+            count(self.alloc_bytes),
+            count(self.peak_bytes),
+            opt(&self.input_path),
+            opt(&self.input_sha256),
+            self.status.to_string(),
+            self.cached.to_string(),
+            opt(&self.error),
+        ]
+        .join(",")
+    }
+}
+
+/// Totals for a run of several parts, e.g. `aoc run --all`.
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Summary {
+    pub parts: usize,
+    pub jobs: usize,
+    pub wall_time_ns: u128,
+    /// The time each part took, added up. It's more than the wall time when parts run at once.
+    pub summed_part_time_ns: u128,
+}
+
+const SUMMARY_CSV_HEADER: &str = "parts,jobs,wall_time_ns,summed_part_time_ns";
+
+impl Summary {
+    pub fn new(outcomes: &[Outcome], jobs: usize, wall_time: Duration) -> Self {
+        Self {
+            parts: outcomes.len(),
+            jobs,
+            wall_time_ns: wall_time.as_nanos(),
+            summed_part_time_ns: outcomes.iter().map(|o| o.elapsed.as_nanos()).sum(),
+        }
+    }
+
+    /// One JSON object, on a single line, with the totals under `summary` to tell it apart from
+    /// the records before it.
+    pub fn to_json(&self) -> String {
+        #[derive(Serialize)]
+        struct Line<'a> {
+            summary: &'a Summary,
+        }
+        serde_json::to_string(&Line { summary: self }).expect("summaries serialize to JSON")
+    }
+
+    pub fn csv_header() -> &'static str {
+        SUMMARY_CSV_HEADER
+    }
+
+    pub fn to_csv(&self) -> String {
+        format!(
+            "{},{},{},{}",
+            self.parts, self.jobs, self.wall_time_ns, self.summed_part_time_ns
+        )
+    }
+}
+
+// Quotes a field if it needs it, per RFC 4180.
+fn csv_field(s: &str) -> String {
+    if s.contains(&[',', '"', '\n', '\r'][..]) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_quotes_csv_fields() {
+        assert_eq!(csv_field("24000"), "24000");
+        assert_eq!(csv_field("#..#\n.##."), "\"#..#\n.##.\"");
+        assert_eq!(csv_field("say \"hi\", ok"), "\"say \"\"hi\"\", ok\"");
+    }
+}
@@ -0,0 +1,253 @@
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use sha2::{Digest, Sha256};
+
+use crate::cache::Cache;
+use crate::days::{solver, Answer, Day, Params, Part, Year};
+use crate::error::SolveError;
+use crate::load_data;
+use crate::memory::{self, AllocStats};
+
+#[derive(Debug)]
+pub enum Status {
+    Solved(Answer),
+    Failed(SolveError),
+    Panicked(String),
+    MissingInput(PathBuf),
+}
+
+#[derive(Debug)]
+pub struct Outcome {
+    pub year: Year,
+    pub day: Day,
+    pub part: Part,
+    pub status: Status,
+    pub elapsed: Duration,
+    pub input: Option<InputInfo>,
+    /// What parsing and solving allocated, when the `alloc-stats` feature is on.
+    pub allocs: Option<AllocStats>,
+    /// Whether the answer was read from the `Cache` rather than solved.
+    pub cached: bool,
+}
+
+/// The input an outcome was solved from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InputInfo {
+    /// `None` when the input didn't come from a file, e.g. stdin.
+    pub path: Option<PathBuf>,
+    pub sha256: String,
+}
+
+impl InputInfo {
+    pub fn new(path: Option<PathBuf>, data: &str) -> Self {
+        let sha256 = Sha256::digest(data.as_bytes())
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect();
+        Self { path, sha256 }
+    }
+}
+
+impl Outcome {
+    pub fn is_ok(&self) -> bool {
+        matches!(self.status, Status::Solved(_) | Status::MissingInput(_))
+    }
+}
+
+// Every implemented (year, day, part), in year then day order.
+pub fn all_parts() -> Vec<(Year, Day, Part)> {
+    crate::days::solvers()
+        .flat_map(|s| s.parts().iter().map(move |&part| (s.year(), s.day(), part)))
+        .collect()
+}
+
+/// Runs `run_part` for each of the `targets` on up to `jobs` threads, returning the outcomes in the
+/// same order as the targets.
+pub fn run_parallel<F>(targets: &[(Year, Day, Part)], jobs: usize, run_part: F) -> Vec<Outcome>
+where
+    F: Fn(Year, Day, Part) -> Outcome + Sync,
+{
+    let next = AtomicUsize::new(0);
+    let mut outcomes = (0..targets.len())
+        .map(|_| None)
+        .collect::<Vec<Option<Outcome>>>();
+
+    thread::scope(|scope| {
+        let workers = (0..jobs.clamp(1, targets.len().max(1)))
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut done = vec![];
+                    loop {
+                        let i = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(&(year, day, part)) = targets.get(i) else {
+                            return done;
+                        };
+                        done.push((i, run_part(year, day, part)));
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        for worker in workers {
+            for (i, outcome) in worker.join().expect("solver panics are caught") {
+                outcomes[i] = Some(outcome);
+            }
+        }
+    });
+
+    outcomes
+        .into_iter()
+        .map(|outcome| outcome.expect("every target is run"))
+        .collect()
+}
+
+// Runs a single day and part against the input at `path`, skipping it if there's no such file.
+pub fn run_part(year: Year, day: Day, part: Part, path: &Path, params: &Params) -> Outcome {
+    run_part_with(year, day, part, path, |data| {
+        run_data(year, day, part, data, params)
+    })
+}
+
+/// As `run_part`, but reuses the answer in `cache` if the input's been solved before, and caches
+/// the answer otherwise.
+pub fn run_part_cached(
+    cache: &Cache,
+    year: Year,
+    day: Day,
+    part: Part,
+    path: &Path,
+    params: &Params,
+) -> Outcome {
+    run_part_with(year, day, part, path, |data| {
+        run_data_cached(cache, year, day, part, data, params)
+    })
+}
+
+fn run_part_with(
+    year: Year,
+    day: Day,
+    part: Part,
+    path: &Path,
+    run: impl FnOnce(String) -> Outcome,
+) -> Outcome {
+    // unimplemented parts are reported as such by `run_data`, whether or not there's input
+    let implemented = solver(year, day).is_some_and(|s| s.parts().contains(&part));
+    if implemented && !path.exists() {
+        return Outcome {
+            year,
+            day,
+            part,
+            status: Status::MissingInput(path.to_path_buf()),
+            elapsed: Duration::ZERO,
+            input: None,
+            allocs: None,
+            cached: false,
+        };
+    }
+
+    match load_data(path.to_path_buf()) {
+        Ok(data) => {
+            let mut outcome = run(data);
+            if let Some(input) = outcome.input.as_mut() {
+                input.path = Some(path.to_path_buf());
+            }
+            outcome
+        }
+        Err(e) => Outcome {
+            year,
+            day,
+            part,
+            status: Status::Failed(e.into()),
+            elapsed: Duration::ZERO,
+            input: None,
+            allocs: None,
+            cached: false,
+        },
+    }
+}
+
+// Runs a single day and part against `data`, catching any panic in the solver so one broken day
+// doesn't take down a whole run. Elapsed time covers parsing and solving, not reading input.
+pub fn run_data(year: Year, day: Day, part: Part, data: String, params: &Params) -> Outcome {
+    let input = InputInfo::new(None, &data);
+    let outcome = |status, elapsed, allocs| Outcome {
+        year,
+        day,
+        part,
+        status,
+        elapsed,
+        input: Some(input),
+        allocs,
+        cached: false,
+    };
+
+    let solver = match solver(year, day) {
+        Some(s) if s.parts().contains(&part) => s,
+        _ => {
+            let e = SolveError::NotImplemented { year, day, part };
+            return outcome(Status::Failed(e), Duration::ZERO, None);
+        }
+    };
+
+    let _span = tracing::info_span!("solve", year, day, part).entered();
+    let start = Instant::now();
+    let (result, allocs) = memory::measure(|| {
+        panic::catch_unwind(AssertUnwindSafe(|| solver.solve(part, data, params)))
+    });
+    let elapsed = start.elapsed();
+
+    let status = match result {
+        Ok(Ok(answer)) => Status::Solved(answer),
+        Ok(Err(e)) => Status::Failed(e),
+        Err(payload) => Status::Panicked(panic_message(payload)),
+    };
+    tracing::info!(?elapsed, ?status, "solved");
+    outcome(status, elapsed, memory::enabled().then_some(allocs))
+}
+
+/// As `run_data`, but reuses the answer in `cache` if `data` has been solved before, and caches
+/// the answer otherwise.
+pub fn run_data_cached(
+    cache: &Cache,
+    year: Year,
+    day: Day,
+    part: Part,
+    data: String,
+    params: &Params,
+) -> Outcome {
+    let input = InputInfo::new(None, &data);
+    if let Some(answer) = cache.get(year, day, part, &input.sha256, params) {
+        return Outcome {
+            year,
+            day,
+            part,
+            status: Status::Solved(answer),
+            elapsed: Duration::ZERO,
+            input: Some(input),
+            allocs: None,
+            cached: true,
+        };
+    }
+
+    let outcome = run_data(year, day, part, data, params);
+    if let Status::Solved(answer) = &outcome.status {
+        if let Err(e) = cache.insert(year, day, part, &input.sha256, params, answer) {
+            tracing::warn!(%e, "couldn't cache answer");
+        }
+    }
+    outcome
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
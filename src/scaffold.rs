@@ -0,0 +1,286 @@
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use crate::days::{Day, Year};
+
+const DAY_TEMPLATE: &str = include_str!("scaffold/day.rs.in");
+const YEAR_TEMPLATE: &str = include_str!("scaffold/year.rs.in");
+const EXPECTED_TEMPLATE: &str = include_str!("scaffold/expected.toml.in");
+
+// Short lists are written on one line, as rustfmt would.
+const LIST_WIDTH: usize = 60;
+const MAX_WIDTH: usize = 100;
+
+/// Creates the files for a new day under the crate `root`: a `src/yearYYYY/dayNN.rs` module with
+/// stub parts and a parser to fill in, and an empty `examples/YYYY/dayNN/example.txt` with an
+/// `expected.toml` to record its answers in. The module is registered in `src/yearYYYY.rs`, which
+/// is created, and registered in turn, for a year's first day. Its parts aren't registered until
+/// they're solved, so they aren't run with the others.
+///
+/// Nothing is written if any of the files already exist or the day is already registered.
+/// Returns the paths written.
+pub fn new_day(
+    root: &Path,
+    year: Year,
+    day: Day,
+    title: &str,
+) -> Result<Vec<PathBuf>, ScaffoldError> {
+    let src = root.join("src");
+    let year_module = format!("year{}", year);
+    let year_path = src.join(format!("{}.rs", year_module));
+    let module_path = src.join(&year_module).join(format!("day{:02}.rs", day));
+    let examples_dir = root
+        .join("examples")
+        .join(year.to_string())
+        .join(format!("day{:02}", day));
+
+    let mut files = vec![
+        (module_path, fill(DAY_TEMPLATE, year, day, title)),
+        (examples_dir.join("example.txt"), String::new()),
+        (
+            examples_dir.join("expected.toml"),
+            EXPECTED_TEMPLATE.to_string(),
+        ),
+    ];
+    let mut edits = vec![];
+    if year_path.exists() {
+        edits.push(edit(&year_path, |s| register_day(s, day))?);
+    } else {
+        files.push((year_path, fill(YEAR_TEMPLATE, year, day, title)));
+        edits.push(edit(&src.join("lib.rs"), |s| {
+            declare_module(s, "year", &year_module)
+        })?);
+        edits.push(edit(&src.join("days.rs"), |s| {
+            add_to_list(
+                s,
+                "static YEARS",
+                &format!("crate::{}::SOLVERS", year_module),
+            )
+        })?);
+    }
+
+    if let Some((path, _)) = files.iter().find(|(path, _)| path.exists()) {
+        return Err(ScaffoldError::Exists(path.clone()));
+    }
+
+    let mut written = vec![];
+    for (path, contents) in files {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)?
+            .write_all(contents.as_bytes())?;
+        written.push(path);
+    }
+    for (path, contents) in edits {
+        fs::write(&path, contents)?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+// The new contents of the source file at `path`, without writing them.
+fn edit(
+    path: &Path,
+    change: impl FnOnce(&str) -> Result<String, String>,
+) -> Result<(PathBuf, String), ScaffoldError> {
+    let source = fs::read_to_string(path)?;
+    let changed =
+        change(&source).map_err(|msg| ScaffoldError::Register(path.to_path_buf(), msg))?;
+    Ok((path.to_path_buf(), changed))
+}
+
+fn fill(template: &str, year: Year, day: Day, title: &str) -> String {
+    template
+        .replace("{{year}}", &year.to_string())
+        .replace("{{day}}", &day.to_string())
+        .replace("{{day02}}", &format!("{:02}", day))
+        .replace(
+            "{{title}}",
+            &title.replace('\\', "\\\\").replace('"', "\\\""),
+        )
+}
+
+// A year module's `source` with `day`'s module declared and its solution added to `SOLVERS`.
+fn register_day(source: &str, day: Day) -> Result<String, String> {
+    let module = format!("day{:02}", day);
+    let source = declare_module(source, "day", &module)?;
+    add_to_list(
+        &source,
+        "pub static SOLVERS",
+        &format!("&{}::Solution", module),
+    )
+}
+
+// `source` with `pub mod <module>;` added among the other modules named `<kind>...`, in order.
+fn declare_module(source: &str, kind: &str, module: &str) -> Result<String, String> {
+    let mut lines: Vec<&str> = source.lines().collect();
+    let modules = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| {
+            let name = line.strip_prefix("pub mod ")?.strip_suffix(';')?;
+            name.starts_with(kind).then_some((i, name))
+        })
+        .collect::<Vec<(usize, &str)>>();
+    if modules.iter().any(|(_, name)| *name == module) {
+        return Err(format!("{} is already registered", module));
+    }
+    let first = modules
+        .first()
+        .ok_or_else(|| format!("couldn't find where to declare {}", module))?
+        .0;
+    let at = modules
+        .iter()
+        .rev()
+        .find(|(_, name)| *name < module)
+        .map_or(first, |(i, _)| i + 1);
+
+    let declaration = format!("pub mod {};", module);
+    lines.insert(at, &declaration);
+    Ok(lines.join("\n") + "\n")
+}
+
+// `source` with `entry` added, in order, to the slice initialised by the statement starting
+// `declaration`, e.g. `static YEARS`.
+fn add_to_list(source: &str, declaration: &str, entry: &str) -> Result<String, String> {
+    let lines: Vec<&str> = source.lines().collect();
+    let not_found = || format!("couldn't find {}", declaration);
+    let start = lines
+        .iter()
+        .position(|line| line.starts_with(declaration))
+        .ok_or_else(not_found)?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| line.ends_with("];"))
+            .ok_or_else(not_found)?;
+
+    let statement = lines[start..=end].join("\n");
+    let (head, list) = statement.split_once("= &[").ok_or_else(not_found)?;
+    let mut entries = list
+        .trim_end_matches("];")
+        .split(',')
+        .map(str::trim)
+        .filter(|e| !e.is_empty())
+        .collect::<Vec<&str>>();
+    if entries.contains(&entry) {
+        return Err(format!("{} is already registered", entry));
+    }
+    entries.push(entry);
+    entries.sort();
+
+    let one_line = format!("{}= &[{}];", head, entries.join(", "));
+    let statement = if entries.join(", ").len() <= LIST_WIDTH && one_line.len() <= MAX_WIDTH {
+        one_line
+    } else {
+        let items = entries
+            .iter()
+            .map(|e| format!("    {},\n", e))
+            .collect::<String>();
+        format!("{}= &[\n{}];", head, items)
+    };
+
+    let mut lines = lines.iter().map(|l| l.to_string()).collect::<Vec<String>>();
+    lines.splice(start..=end, [statement]);
+    Ok(lines.join("\n") + "\n")
+}
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    Io(io::Error),
+    Exists(PathBuf),
+    Register(PathBuf, String),
+}
+
+impl fmt::Display for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ScaffoldError::Io(e) => write!(f, "couldn't create day: {}", e),
+            ScaffoldError::Exists(path) => write!(f, "{} already exists", path.display()),
+            ScaffoldError::Register(path, msg) => {
+                write!(f, "couldn't register in {}: {}", path.display(), msg)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {}
+
+impl From<io::Error> for ScaffoldError {
+    fn from(e: io::Error) -> Self {
+        ScaffoldError::Io(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const YEAR: &str = "\
+use crate::days::Solver;
+
+pub mod day01;
+pub mod day03;
+
+pub static SOLVERS: &[&dyn Solver] = &[&day01::Solution, &day03::Solution];
+";
+
+    #[test]
+    fn it_registers_days_in_order() {
+        assert_eq!(
+            register_day(YEAR, 2).unwrap(),
+            "\
+use crate::days::Solver;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+
+pub static SOLVERS: &[&dyn Solver] = &[&day01::Solution, &day02::Solution, &day03::Solution];
+"
+        );
+        assert!(register_day(YEAR, 4)
+            .unwrap()
+            .contains("day03;\npub mod day04;"));
+        assert!(register_day(YEAR, 3).is_err());
+    }
+
+    #[test]
+    fn it_wraps_long_lists() {
+        let registered = (4..=5).fold(YEAR.to_string(), |s, day| register_day(&s, day).unwrap());
+        assert!(registered.contains(
+            "\
+pub static SOLVERS: &[&dyn Solver] = &[
+    &day01::Solution,
+    &day03::Solution,
+    &day04::Solution,
+    &day05::Solution,
+];
+"
+        ));
+        assert_eq!(
+            register_day(&registered, 2)
+                .unwrap()
+                .matches("::Solution,\n")
+                .count(),
+            5
+        );
+    }
+
+    #[test]
+    fn it_declares_modules_among_their_kind() {
+        let lib = "pub mod answer;\npub mod watch;\npub mod year2022;\n";
+        assert_eq!(
+            declare_module(lib, "year", "year2015").unwrap(),
+            "pub mod answer;\npub mod watch;\npub mod year2015;\npub mod year2022;\n"
+        );
+        assert!(declare_module(lib, "year", "year2022").is_err());
+        assert!(declare_module(lib, "day", "day01").is_err());
+    }
+}
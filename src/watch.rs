@@ -0,0 +1,91 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::time::Duration;
+
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+
+/// Watches a set of files for changes.
+///
+/// Each file's directory is watched rather than the file itself, so files that editors save by
+/// replacing them, or that don't exist yet, are still picked up.
+pub struct FileWatcher {
+    // (as given, absolute) pairs, so changes are reported with the paths we were given
+    files: Vec<(PathBuf, PathBuf)>,
+    events: Receiver<notify::Result<Event>>,
+    _watcher: RecommendedWatcher,
+}
+
+impl FileWatcher {
+    pub fn new(files: &[PathBuf]) -> notify::Result<Self> {
+        let (tx, events) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(tx)?;
+
+        let mut watched_dirs = vec![];
+        let mut watched_files = vec![];
+        for file in files {
+            let absolute = absolute(file)?;
+            let dir = absolute.parent().unwrap_or(Path::new("/")).to_path_buf();
+            if !watched_dirs.contains(&dir) {
+                watcher.watch(&dir, RecursiveMode::NonRecursive)?;
+                watched_dirs.push(dir);
+            }
+            watched_files.push((file.clone(), absolute));
+        }
+
+        Ok(Self {
+            files: watched_files,
+            events,
+            _watcher: watcher,
+        })
+    }
+
+    /// Blocks until at least one of the files changes, returning the ones that did. Changes that
+    /// arrive within `settle` of each other are reported together, as editors often save a file
+    /// in several steps.
+    pub fn wait(&self, settle: Duration) -> notify::Result<Vec<PathBuf>> {
+        let mut changed = vec![];
+        while changed.is_empty() {
+            let event = self
+                .events
+                .recv()
+                .map_err(|_| notify::Error::generic("file watcher stopped"))?;
+            self.note_changes(event?, &mut changed);
+
+            loop {
+                match self.events.recv_timeout(settle) {
+                    Ok(event) => self.note_changes(event?, &mut changed),
+                    Err(RecvTimeoutError::Timeout) => break,
+                    Err(RecvTimeoutError::Disconnected) => {
+                        return Err(notify::Error::generic("file watcher stopped"))
+                    }
+                }
+            }
+        }
+        Ok(changed)
+    }
+
+    fn note_changes(&self, event: Event, changed: &mut Vec<PathBuf>) {
+        if matches!(event.kind, EventKind::Access(_)) {
+            return;
+        }
+        for (given, absolute) in &self.files {
+            if event.paths.contains(absolute) && !changed.contains(given) {
+                changed.push(given.clone());
+            }
+        }
+    }
+}
+
+// `path` made absolute, resolving symlinks in its directory, which must exist. Watchers report
+// events against the resolved directory.
+fn absolute(path: &Path) -> notify::Result<PathBuf> {
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| notify::Error::generic(&format!("{} isn't a file", path.display())))?;
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    Ok(fs::canonicalize(dir)?.join(file_name))
+}
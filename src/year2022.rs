@@ -0,0 +1,37 @@
+use crate::days::Solver;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+
+pub static SOLVERS: &[&dyn Solver] = &[
+    &day01::Solution,
+    &day02::Solution,
+    &day03::Solution,
+    &day04::Solution,
+    &day05::Solution,
+    &day06::Solution,
+    &day07::Solution,
+    &day08::Solution,
+    &day09::Solution,
+    &day10::Solution,
+    &day11::Solution,
+    &day12::Solution,
+    &day13::Solution,
+    &day14::Solution,
+    &day15::Solution,
+    &day16::Solution,
+];
@@ -0,0 +1,125 @@
+use crate::days::{Answer, Day, Params, Part, Puzzle, Year};
+use crate::error::{ParseError, SolveError};
+
+type Calories = u32;
+type ElfSupplies = Vec<Calories>;
+
+fn solve_part1(elves: &[ElfSupplies]) -> Result<Answer, SolveError> {
+    elves
+        .iter()
+        .map(&total_elf_calories)
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .max()
+        .map(Answer::from)
+        .ok_or_else(|| SolveError::unsolvable("no elves found"))
+}
+
+fn solve_part2(elves: &[ElfSupplies]) -> Result<Answer, SolveError> {
+    let mut elf_cals = elves
+        .iter()
+        .map(&total_elf_calories)
+        .collect::<Result<Vec<Calories>, _>>()?;
+
+    elf_cals.sort();
+
+    sum_calories(elf_cals.iter().rev().take(3)).map(Answer::from)
+}
+
+const EXAMPLE: &str = include_str!("../../examples/2022/day01/example.txt");
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    type Input = Vec<ElfSupplies>;
+
+    fn year(&self) -> Year {
+        2022
+    }
+
+    fn day(&self) -> Day {
+        1
+    }
+
+    fn title(&self) -> &'static str {
+        "Calorie Counting"
+    }
+
+    fn example(&self) -> &'static str {
+        EXAMPLE
+    }
+
+    fn parse(&self, data: &str) -> Result<Self::Input, SolveError> {
+        parse(data)
+    }
+
+    fn solve(
+        &self,
+        part: Part,
+        elves: &Self::Input,
+        _params: &Params,
+    ) -> Result<Answer, SolveError> {
+        match part {
+            1 => solve_part1(elves),
+            _ => solve_part2(elves),
+        }
+    }
+}
+
+fn parse(data: &str) -> Result<Vec<ElfSupplies>, SolveError> {
+    let mut elves: Vec<ElfSupplies>;
+    let last_elf: ElfSupplies;
+
+    (elves, last_elf) = data
+        .split("\n")
+        .map(|line| parse_calories(data, line))
+        .collect::<Result<Vec<Option<Calories>>, SolveError>>()?
+        .into_iter()
+        .fold((Vec::new(), Vec::new()), accumulate_calories);
+    if !last_elf.is_empty() {
+        elves.push(last_elf);
+    }
+
+    Ok(elves)
+}
+
+fn accumulate_calories(
+    state: (Vec<ElfSupplies>, ElfSupplies),
+    calories: Option<Calories>,
+) -> (Vec<ElfSupplies>, ElfSupplies) {
+    let (mut all_elves, mut current_elf) = state;
+
+    match calories {
+        Some(cal) => {
+            current_elf.push(cal);
+        }
+        None => {
+            all_elves.push(current_elf);
+            current_elf = Vec::new()
+        }
+    };
+    (all_elves, current_elf)
+}
+
+fn parse_calories(source: &str, calories: &str) -> Result<Option<Calories>, SolveError> {
+    if calories.is_empty() {
+        Ok(None)
+    } else {
+        calories
+            .parse::<Calories>()
+            .map(Some)
+            .map_err(|e| ParseError::at(source, calories, format!("bad calories: {}", e)).into())
+    }
+}
+
+fn total_elf_calories(supplies: &ElfSupplies) -> Result<Calories, SolveError> {
+    sum_calories(supplies.iter())
+}
+
+fn sum_calories<'a>(
+    mut calories: impl Iterator<Item = &'a Calories>,
+) -> Result<Calories, SolveError> {
+    calories
+        .try_fold(0, |total: Calories, &cals| total.checked_add(cals))
+        .ok_or_else(|| SolveError::unsolvable("too many calories to count"))
+}
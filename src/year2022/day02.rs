@@ -0,0 +1,287 @@
+use crate::days::{Answer, Day, Params, Part, Puzzle, Year};
+use crate::error::SolveError;
+
+fn solve_part1(guide: &[GuideLine]) -> Answer {
+    guide
+        .iter()
+        .map(|line| round_part1(line).score())
+        .sum::<Score>()
+        .into()
+}
+
+fn solve_part2(guide: &[GuideLine]) -> Answer {
+    guide
+        .iter()
+        .map(|line| round_part2(line).score())
+        .sum::<Score>()
+        .into()
+}
+
+const EXAMPLE: &str = include_str!("../../examples/2022/day02/example.txt");
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    type Input = Vec<GuideLine>;
+
+    fn year(&self) -> Year {
+        2022
+    }
+
+    fn day(&self) -> Day {
+        2
+    }
+
+    fn title(&self) -> &'static str {
+        "Rock Paper Scissors"
+    }
+
+    fn example(&self) -> &'static str {
+        EXAMPLE
+    }
+
+    fn parse(&self, data: &str) -> Result<Self::Input, SolveError> {
+        parse(data)
+    }
+
+    fn solve(
+        &self,
+        part: Part,
+        guide: &Self::Input,
+        _params: &Params,
+    ) -> Result<Answer, SolveError> {
+        match part {
+            1 => Ok(solve_part1(guide)),
+            _ => Ok(solve_part2(guide)),
+        }
+    }
+}
+
+use Move::*;
+use Outcome::*;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Move {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+#[derive(Debug, PartialEq)]
+enum Outcome {
+    Win,
+    Loss,
+    Draw,
+}
+
+// The second column of the strategy guide, which means something different in each part
+#[derive(Debug, PartialEq)]
+pub enum Column {
+    X,
+    Y,
+    Z,
+}
+
+type GuideLine = (Move, Column);
+
+type Score = u32;
+
+#[derive(Debug, PartialEq)]
+struct Round {
+    them: Move,
+    us: Move,
+}
+
+impl Round {
+    fn new(them: Move, us: Move) -> Self {
+        Self { them, us }
+    }
+
+    fn new_desired(them: Move, desired_outcome: Outcome) -> Self {
+        let us = Round::move_for_desired_outcome(&them, desired_outcome);
+        Round::new(them, us)
+    }
+
+    fn move_for_desired_outcome(them: &Move, desired_outcome: Outcome) -> Move {
+        match (them, desired_outcome) {
+            (Rock, Win) => Paper,
+            (Rock, Loss) => Scissors,
+            (Rock, Draw) => Rock,
+            (Paper, Win) => Scissors,
+            (Paper, Loss) => Rock,
+            (Paper, Draw) => Paper,
+            (Scissors, Win) => Rock,
+            (Scissors, Loss) => Paper,
+            (Scissors, Draw) => Scissors,
+        }
+    }
+
+    fn outcome(&self) -> Outcome {
+        match (&self.them, &self.us) {
+            (Rock, Rock) => Draw,
+            (Rock, Paper) => Win,
+            (Rock, Scissors) => Loss,
+            (Paper, Rock) => Loss,
+            (Paper, Paper) => Draw,
+            (Paper, Scissors) => Win,
+            (Scissors, Rock) => Win,
+            (Scissors, Paper) => Loss,
+            (Scissors, Scissors) => Draw,
+        }
+    }
+
+    fn move_score(&self) -> Score {
+        match self.us {
+            Rock => 1,
+            Paper => 2,
+            Scissors => 3,
+        }
+    }
+
+    fn outcome_score(&self) -> Score {
+        match self.outcome() {
+            Win => 6,
+            Draw => 3,
+            Loss => 0,
+        }
+    }
+
+    fn score(&self) -> Score {
+        self.outcome_score() + self.move_score()
+    }
+}
+
+fn parse_them_move(mv: &str) -> Result<Move, SolveError> {
+    match mv {
+        "A" => Ok(Rock),
+        "B" => Ok(Paper),
+        "C" => Ok(Scissors),
+        m => Err(SolveError::parse(format!("illegal move: {:?}", m))),
+    }
+}
+
+fn parse_column(column: &str) -> Result<Column, SolveError> {
+    match column {
+        "X" => Ok(Column::X),
+        "Y" => Ok(Column::Y),
+        "Z" => Ok(Column::Z),
+        c => Err(SolveError::parse(format!("illegal column: {:?}", c))),
+    }
+}
+
+fn split_line(line: &str) -> Result<(&str, &str), SolveError> {
+    let mut columns = line.split_whitespace();
+    match (columns.next(), columns.next(), columns.next()) {
+        (Some(first), Some(second), None) => Ok((first, second)),
+        _ => Err(SolveError::parse("expected a round like \"A Y\"")),
+    }
+}
+
+fn parse_line(line: &str) -> Result<GuideLine, SolveError> {
+    let (them, column) = split_line(line)?;
+    Ok((parse_them_move(them)?, parse_column(column)?))
+}
+
+fn parse(data: &str) -> Result<Vec<GuideLine>, SolveError> {
+    data.lines()
+        .map(|line| parse_line(line).map_err(|e| e.located(data, line)))
+        .collect()
+}
+
+fn us_move(column: &Column) -> Move {
+    match column {
+        Column::X => Rock,
+        Column::Y => Paper,
+        Column::Z => Scissors,
+    }
+}
+
+fn desired_outcome(column: &Column) -> Outcome {
+    match column {
+        Column::X => Loss,
+        Column::Y => Draw,
+        Column::Z => Win,
+    }
+}
+
+fn round_part1((them, column): &GuideLine) -> Round {
+    Round::new(*them, us_move(column))
+}
+
+fn round_part2((them, column): &GuideLine) -> Round {
+    Round::new_desired(*them, desired_outcome(column))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_calcualtes_score() {
+        // wins
+        assert_eq!(Round::new(Scissors, Rock).score(), 6 + 1);
+        assert_eq!(Round::new(Rock, Paper).score(), 6 + 2);
+        assert_eq!(Round::new(Paper, Scissors).score(), 6 + 3);
+
+        // losses
+        assert_eq!(Round::new(Paper, Rock).score(), 0 + 1);
+        assert_eq!(Round::new(Scissors, Paper).score(), 0 + 2);
+        assert_eq!(Round::new(Rock, Scissors).score(), 0 + 3);
+
+        // draws
+        assert_eq!(Round::new(Rock, Rock).score(), 3 + 1);
+        assert_eq!(Round::new(Paper, Paper).score(), 3 + 2);
+        assert_eq!(Round::new(Scissors, Scissors).score(), 3 + 3);
+    }
+
+    #[test]
+    fn it_parses_their_move() {
+        assert_eq!(parse_them_move("A").unwrap(), Rock);
+        assert_eq!(parse_them_move("B").unwrap(), Paper);
+        assert_eq!(parse_them_move("C").unwrap(), Scissors);
+    }
+
+    #[test]
+    fn it_parses_our_move() {
+        assert_eq!(us_move(&parse_column("X").unwrap()), Rock);
+        assert_eq!(us_move(&parse_column("Y").unwrap()), Paper);
+        assert_eq!(us_move(&parse_column("Z").unwrap()), Scissors);
+    }
+
+    #[test]
+    fn it_parses_part1_input_lines() {
+        assert_eq!(
+            round_part1(&parse_line("A Y ").unwrap()),
+            Round::new(Rock, Paper)
+        );
+        assert_eq!(
+            round_part1(&parse_line("B X ").unwrap()),
+            Round::new(Paper, Rock)
+        );
+        assert_eq!(
+            round_part1(&parse_line("C Z ").unwrap()),
+            Round::new(Scissors, Scissors)
+        );
+    }
+
+    #[test]
+    fn it_parses_part2_input_lines() {
+        //draw
+        assert_eq!(
+            round_part2(&parse_line("A Y ").unwrap()),
+            Round::new(Rock, Rock)
+        );
+
+        // loss
+        assert_eq!(
+            round_part2(&parse_line("B X ").unwrap()),
+            Round::new(Paper, Rock)
+        );
+
+        // win
+        assert_eq!(
+            round_part2(&parse_line("C Z ").unwrap()),
+            Round::new(Scissors, Rock)
+        );
+    }
+}
@@ -0,0 +1,183 @@
+use crate::days::{Answer, Day, Params, Part, Puzzle, Year};
+use crate::error::{ParseError, SolveError};
+
+fn solve_part1(rucksacks: &[Rucksack]) -> Result<Answer, SolveError> {
+    rucksacks
+        .iter()
+        .map(|rucksack| rucksack.find_dup().and_then(item_priority))
+        .sum::<Result<Priority, SolveError>>()
+        .map(Answer::from)
+}
+
+fn solve_part2(rucksacks: &[Rucksack]) -> Result<Answer, SolveError> {
+    rucksacks
+        .chunks(3)
+        .map(|group| find_dup_rucksack_item(group).and_then(item_priority))
+        .sum::<Result<Priority, SolveError>>()
+        .map(Answer::from)
+}
+
+const EXAMPLE: &str = include_str!("../../examples/2022/day03/example.txt");
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    type Input = Vec<Rucksack>;
+
+    fn year(&self) -> Year {
+        2022
+    }
+
+    fn day(&self) -> Day {
+        3
+    }
+
+    fn title(&self) -> &'static str {
+        "Rucksack Reorganization"
+    }
+
+    fn example(&self) -> &'static str {
+        EXAMPLE
+    }
+
+    fn parse(&self, data: &str) -> Result<Self::Input, SolveError> {
+        parse(data)
+    }
+
+    fn solve(
+        &self,
+        part: Part,
+        rucksacks: &Self::Input,
+        _params: &Params,
+    ) -> Result<Answer, SolveError> {
+        match part {
+            1 => solve_part1(rucksacks),
+            _ => solve_part2(rucksacks),
+        }
+    }
+}
+
+use std::collections::HashSet;
+
+type Item = char;
+type Priority = u32;
+
+#[derive(Debug, PartialEq)]
+pub struct Rucksack {
+    all_items: HashSet<Item>,
+    compartments: Vec<HashSet<Item>>,
+}
+
+impl Rucksack {
+    fn find_dup(&self) -> Result<Item, SolveError> {
+        self.compartments[0]
+            .intersection(&self.compartments[1])
+            .next()
+            .copied()
+            .ok_or_else(|| SolveError::unsolvable("no item in both compartments"))
+    }
+}
+
+fn parse(data: &str) -> Result<Vec<Rucksack>, SolveError> {
+    let mut rucksacks = vec![];
+    for line in data.lines() {
+        if let Some((i, c)) = line.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+            let msg = format!("invalid item: {:?}", c);
+            return Err(ParseError::at(data, &line[i..], msg).into());
+        }
+        rucksacks.push(parse_line(line));
+    }
+    Ok(rucksacks)
+}
+
+fn parse_line(line: &str) -> Rucksack {
+    let (contents1, contents2) = line.split_at(line.len() / 2);
+    Rucksack {
+        all_items: HashSet::from_iter(line.chars()),
+        compartments: vec![
+            HashSet::from_iter(contents1.chars()),
+            HashSet::from_iter(contents2.chars()),
+        ],
+    }
+}
+
+fn find_dup_rucksack_item(rucksacks: &[Rucksack]) -> Result<Item, SolveError> {
+    rucksacks
+        .iter()
+        .map(|rucksack| rucksack.all_items.clone())
+        .reduce(|dups, rucksack_items| &dups & &rucksack_items)
+        .and_then(|dups| dups.into_iter().next())
+        .ok_or_else(|| SolveError::unsolvable("no item common to the group"))
+}
+
+const LOWER_CASE_ASCII_OFFSET: u32 = 96;
+const UPPER_CASE_ASCII_OFFSET: u32 = 38;
+
+fn item_priority(item: Item) -> Result<Priority, SolveError> {
+    if item >= 'a' && item <= 'z' {
+        Ok((item as u32) - LOWER_CASE_ASCII_OFFSET)
+    } else if item >= 'A' && item <= 'Z' {
+        Ok((item as u32) - UPPER_CASE_ASCII_OFFSET)
+    } else {
+        Err(SolveError::parse(format!("invalid item: {:?}", item)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_parses_line() {
+        let line = "vJrwpWtwJgWrhcsFMMfFFhFp";
+        let compartment1 = "vJrwpWtwJgWr";
+        let compartment2 = "hcsFMMfFFhFp";
+        let rucksack = parse_line(line);
+
+        assert_eq!(
+            rucksack,
+            Rucksack {
+                all_items: HashSet::from_iter(line.chars()),
+                compartments: vec![
+                    HashSet::from_iter(compartment1.chars()),
+                    HashSet::from_iter(compartment2.chars())
+                ]
+            }
+        )
+    }
+
+    #[test]
+    fn it_finds_common_item_in_both_rucksack_compartments() {
+        let rucksack = Rucksack {
+            all_items: HashSet::from_iter("jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL".chars()),
+            compartments: vec![
+                HashSet::from_iter("jqHRNqRjqzjGDLGL".chars()),
+                HashSet::from_iter("rsFMfFZSrLrFZsSL".chars()),
+            ],
+        };
+        assert_eq!(rucksack.find_dup().unwrap(), 'L');
+    }
+
+    #[test]
+    fn it_finds_common_item_among_multiple_rucksacks() {
+        let r1 = parse_line("vJrwpWtwJgWrhcsFMMfFFhFp");
+        let r2 = parse_line("jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL");
+        let r3 = parse_line("PmmdzqPrVvPwwTWBwg");
+        let rucksacks = [r1, r2, r3];
+        assert_eq!(find_dup_rucksack_item(&rucksacks).unwrap(), 'r');
+
+        let r1 = parse_line("wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn");
+        let r2 = parse_line("ttgJtRGJQctTZtZT");
+        let r3 = parse_line("CrZsJsPPZsGzwwsLwLmpwMDw");
+        let rucksacks = [r1, r2, r3];
+        assert_eq!(find_dup_rucksack_item(&rucksacks).unwrap(), 'Z');
+    }
+
+    #[test]
+    fn it_calculates_priority_of_item() {
+        assert_eq!(item_priority('a').unwrap(), 1);
+        assert_eq!(item_priority('z').unwrap(), 26);
+        assert_eq!(item_priority('A').unwrap(), 27);
+        assert_eq!(item_priority('Z').unwrap(), 52);
+    }
+}
@@ -0,0 +1,115 @@
+use crate::days::{Answer, Day, Params, Part, Puzzle, Year};
+use crate::error::{ParseError, SolveError};
+
+fn solve_part1(assignments: &[Assignment]) -> Result<Answer, SolveError> {
+    Ok(assignments
+        .iter()
+        .filter(|ranges| is_full_overlap(ranges))
+        .count()
+        .into())
+}
+
+fn solve_part2(assignments: &[Assignment]) -> Result<Answer, SolveError> {
+    Ok(assignments
+        .iter()
+        .filter(|ranges| is_partial_overlap(ranges))
+        .count()
+        .into())
+}
+
+const EXAMPLE: &str = include_str!("../../examples/2022/day04/example.txt");
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    type Input = Vec<Assignment>;
+
+    fn year(&self) -> Year {
+        2022
+    }
+
+    fn day(&self) -> Day {
+        4
+    }
+
+    fn title(&self) -> &'static str {
+        "Camp Cleanup"
+    }
+
+    fn example(&self) -> &'static str {
+        EXAMPLE
+    }
+
+    fn parse(&self, data: &str) -> Result<Self::Input, SolveError> {
+        parse(data)
+    }
+
+    fn solve(
+        &self,
+        part: Part,
+        assignments: &Self::Input,
+        _params: &Params,
+    ) -> Result<Answer, SolveError> {
+        match part {
+            1 => solve_part1(assignments),
+            _ => solve_part2(assignments),
+        }
+    }
+}
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use std::ops::RangeInclusive;
+
+type Section = u32;
+type Assignment = (RangeInclusive<Section>, RangeInclusive<Section>);
+
+fn parse(data: &str) -> Result<Vec<Assignment>, SolveError> {
+    data.lines().map(|line| parse_line(data, line)).collect()
+}
+
+fn parse_line(source: &str, line: &str) -> Result<Assignment, SolveError> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new(r"^(\d+)-(\d+),(\d+)-(\d+)$").unwrap();
+    }
+    let caps = RE
+        .captures(line)
+        .ok_or_else(|| ParseError::at(source, line, "expected an assignment like \"2-4,6-8\""))?;
+    let section = |i: usize| {
+        let m = caps.get(i).unwrap().as_str();
+        m.parse::<Section>()
+            .map_err(|e| ParseError::at(source, m, format!("bad section: {}", e)))
+    };
+
+    let s1 = section(1)?..=section(2)?;
+    let s2 = section(3)?..=section(4)?;
+
+    Ok((s1, s2))
+}
+
+fn is_full_overlap(ranges: &(RangeInclusive<Section>, RangeInclusive<Section>)) -> bool {
+    let (r1, r2) = ranges;
+    let (r1_s, r1_e) = r1.clone().into_inner();
+    let (r2_s, r2_e) = r2.clone().into_inner();
+
+    (r1_s <= r2_s && r1_e >= r2_e) || (r2_s <= r1_s && r2_e >= r1_e)
+}
+
+fn is_partial_overlap(ranges: &(RangeInclusive<Section>, RangeInclusive<Section>)) -> bool {
+    let (r1, r2) = ranges;
+    let (r1_s, r1_e) = r1.clone().into_inner();
+    let (r2_s, r2_e) = r2.clone().into_inner();
+
+    r1.contains(&r2_s) || r1.contains(&r2_e) || r2.contains(&r1_s) || r2.contains(&r1_e)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_parses_input_line() {
+        assert_eq!(parse_line("2-4,6-8", "2-4,6-8").unwrap(), (2..=4, 6..=8))
+    }
+}
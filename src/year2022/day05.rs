@@ -0,0 +1,328 @@
+use crate::check;
+use crate::days::{Answer, Day, Params, Part, Puzzle, Year};
+use crate::error::{ParseError, SolveError};
+
+const EXAMPLE: &str = include_str!("../../examples/2022/day05/example.txt");
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    type Input = Procedure;
+
+    fn year(&self) -> Year {
+        2022
+    }
+
+    fn day(&self) -> Day {
+        5
+    }
+
+    fn title(&self) -> &'static str {
+        "Supply Stacks"
+    }
+
+    fn example(&self) -> &'static str {
+        EXAMPLE
+    }
+
+    // the crate diagram's rows are padded with spaces, so trailing whitespace is expected
+    fn check(&self, data: &str) -> Vec<ParseError> {
+        check::line_endings(data)
+    }
+
+    fn parse(&self, data: &str) -> Result<Self::Input, SolveError> {
+        parse(data)
+    }
+
+    fn solve(
+        &self,
+        part: Part,
+        procedure: &Self::Input,
+        _params: &Params,
+    ) -> Result<Answer, SolveError> {
+        match part {
+            1 => run(procedure, &Crane::KrateMover9000),
+            _ => run(procedure, &Crane::KrateMover9001),
+        }
+    }
+}
+
+fn run(procedure: &Procedure, crane: &Crane) -> Result<Answer, SolveError> {
+    let mut stacks = procedure.stacks.clone();
+    run_instructions(&mut stacks, &procedure.instructions, crane)?;
+    stacks.head_krates().map(Answer::from)
+}
+
+type Krate = char;
+type StackName = char;
+type Instruction = (u32, (StackName, StackName));
+
+pub struct Procedure {
+    stacks: Stacks,
+    instructions: Vec<Instruction>,
+}
+
+enum Crane {
+    KrateMover9000,
+    KrateMover9001,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+struct Stacks {
+    stack_names: Vec<StackName>,
+    stack_map: HashMap<StackName, Vec<Krate>>,
+}
+
+impl Stacks {
+    fn new(stack_names: Vec<StackName>) -> Self {
+        let mut stack_map = HashMap::new();
+        for name in stack_names.clone() {
+            stack_map.insert(name, vec![]);
+        }
+        Self {
+            stack_names,
+            stack_map,
+        }
+    }
+
+    fn add_krate(&mut self, stack_name: &StackName, krate: &Krate) {
+        self.stack_map.get_mut(&stack_name).unwrap().push(*krate);
+    }
+
+    fn move_krates(
+        &mut self,
+        from_stack: &StackName,
+        to_stack: &StackName,
+        amount: u32,
+        crane: &Crane,
+    ) -> Result<(), SolveError> {
+        if !self.stack_map.contains_key(to_stack) {
+            return Err(SolveError::Unsolvable(format!(
+                "no such stack: {}",
+                to_stack
+            )));
+        }
+
+        let from = self
+            .stack_map
+            .get_mut(from_stack)
+            .ok_or_else(|| SolveError::Unsolvable(format!("no such stack: {}", from_stack)))?;
+
+        let remaining = from.len().checked_sub(amount as usize).ok_or_else(|| {
+            SolveError::Unsolvable(format!(
+                "can't move {} krates from stack {} holding {}",
+                amount,
+                from_stack,
+                from.len()
+            ))
+        })?;
+        let mut popped = from.split_off(remaining);
+
+        match crane {
+            Crane::KrateMover9000 => popped.reverse(),
+            Crane::KrateMover9001 => (), // no-op already in correct order
+        }
+
+        self.stack_map
+            .get_mut(to_stack)
+            .unwrap()
+            .append(&mut popped);
+        Ok(())
+    }
+
+    fn head_krates(&self) -> Result<String, SolveError> {
+        let mut s = String::new();
+        for stack_name in &self.stack_names {
+            let stack = self.stack_map.get(&stack_name).unwrap();
+            let krate = stack
+                .last()
+                .ok_or_else(|| SolveError::Unsolvable(format!("stack {} is empty", stack_name)))?;
+            s.push(*krate);
+        }
+        Ok(s)
+    }
+}
+
+use std::collections::HashMap;
+
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{anychar, char, u32},
+    combinator::map,
+    error::context,
+    multi::separated_list0,
+    sequence::{delimited, preceded, separated_pair},
+};
+
+use crate::parser::parse_all_in;
+
+fn parse(data: &str) -> Result<Procedure, SolveError> {
+    let mut lines = data.lines();
+    let stacks = parse_header(data, &mut lines)?;
+    let instructions = lines
+        .map(|line| parse_instruction(data, line))
+        .collect::<Result<Vec<Instruction>, SolveError>>()?;
+    Ok(Procedure {
+        stacks,
+        instructions,
+    })
+}
+
+fn parse_header<'a>(
+    source: &'a str,
+    lines: &mut impl Iterator<Item = &'a str>,
+) -> Result<Stacks, SolveError> {
+    let mut header_lines = lines
+        .take_while(|line| !line.is_empty())
+        .collect::<Vec<&str>>();
+    header_lines.reverse();
+
+    let (names_line, stack_lines) = header_lines
+        .split_first()
+        .ok_or_else(|| SolveError::parse("missing stacks header"))?;
+
+    let stack_names = parse_stack_names(source, names_line)?;
+    let mut stacks = Stacks::new(stack_names.clone());
+
+    for line in stack_lines.iter() {
+        let krates = parse_header_stack_line(source, line)?;
+        for (stack_name, krate) in stack_names.iter().zip(krates.iter()) {
+            if let Some(k) = krate {
+                stacks.add_krate(stack_name, k);
+            }
+        }
+    }
+    Ok(stacks)
+}
+
+fn run_instructions<'a>(
+    stacks: &'a mut Stacks,
+    instructions: &[Instruction],
+    crane: &Crane,
+) -> Result<&'a mut Stacks, SolveError> {
+    for (amount, (from_stack, to_stack)) in instructions {
+        stacks.move_krates(from_stack, to_stack, *amount, crane)?;
+    }
+    Ok(stacks)
+}
+
+fn parse_stack_names(source: &str, input: &str) -> Result<Vec<StackName>, SolveError> {
+    let label_parser = context("stack name", delimited(char(' '), anychar, char(' ')));
+    let stack_names_parser = separated_list0(char(' '), label_parser);
+    Ok(parse_all_in(source, input, stack_names_parser)?)
+}
+
+fn parse_header_stack_line(source: &str, input: &str) -> Result<Vec<Option<Krate>>, SolveError> {
+    let krate_parser = map(delimited(char('['), anychar, char(']')), |c| Some(c));
+    let placeholder_parser = map(tag("   "), |_| None);
+    let position_parser = context("krate", alt((krate_parser, placeholder_parser)));
+    let krates_parser = separated_list0(char(' '), position_parser);
+    Ok(parse_all_in(source, input, krates_parser)?)
+}
+
+fn parse_instruction(source: &str, input: &str) -> Result<Instruction, SolveError> {
+    let stacks_parser = separated_pair(anychar, tag(" to "), anychar);
+    let ins_parser = separated_pair(u32, tag(" from "), stacks_parser);
+    let parser = context("instruction", preceded(tag("move "), ins_parser));
+    Ok(parse_all_in(source, input, parser)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_parses_stack_names() {
+        let labels = " 1   2   3 ";
+        assert_eq!(
+            parse_stack_names(labels, labels).unwrap(),
+            vec!['1', '2', '3']
+        );
+    }
+
+    #[test]
+    fn it_parses_header_stack_line() {
+        let stack_line = "    [D]    "; // empty/stack/empty
+        assert_eq!(
+            parse_header_stack_line(stack_line, stack_line).unwrap(),
+            vec![None, Some('D'), None]
+        )
+    }
+
+    #[test]
+    fn it_parses_header_lines() {
+        let data = "    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 ";
+        let header = parse_header(data, &mut data.lines()).unwrap();
+        assert_eq!(header.stack_names, vec!['1', '2', '3']);
+        assert_eq!(header.stack_map.get(&'1').unwrap(), &vec!['Z', 'N']);
+        assert_eq!(header.stack_map.get(&'2').unwrap(), &vec!['M', 'C', 'D']);
+        assert_eq!(header.stack_map.get(&'3').unwrap(), &vec!['P']);
+    }
+
+    #[test]
+    fn it_moves_krates_part1() {
+        let mut stacks = Stacks {
+            stack_names: vec!['1', '2', '3'],
+            stack_map: HashMap::from([
+                ('1', vec!['Z', 'N']),
+                ('2', vec!['M', 'C', 'D']),
+                ('3', vec!['P']),
+            ]),
+        };
+
+        stacks
+            .move_krates(&'2', &'3', 2, &Crane::KrateMover9000)
+            .unwrap();
+        assert_eq!(stacks.stack_map.get(&'2').unwrap(), &vec!['M']);
+        assert_eq!(stacks.stack_map.get(&'3').unwrap(), &vec!['P', 'D', 'C']); // D/C reverses order
+
+        stacks
+            .move_krates(&'3', &'1', 3, &Crane::KrateMover9000)
+            .unwrap();
+        assert!(stacks.stack_map.get(&'3').unwrap().is_empty());
+        assert_eq!(
+            stacks.stack_map.get(&'1').unwrap(),
+            &vec!['Z', 'N', 'C', 'D', 'P'] // C/D/P reverses order
+        );
+    }
+
+    #[test]
+    fn it_moves_krates_part2() {
+        let mut stacks = Stacks {
+            stack_names: vec!['1', '2', '3'],
+            stack_map: HashMap::from([
+                ('1', vec!['Z', 'N']),
+                ('2', vec!['M', 'C', 'D']),
+                ('3', vec!['P']),
+            ]),
+        };
+
+        stacks
+            .move_krates(&'2', &'3', 2, &Crane::KrateMover9001)
+            .unwrap();
+        assert_eq!(stacks.stack_map.get(&'2').unwrap(), &vec!['M']);
+        assert_eq!(stacks.stack_map.get(&'3').unwrap(), &vec!['P', 'C', 'D']); // C/D keeps order
+
+        stacks
+            .move_krates(&'3', &'1', 3, &Crane::KrateMover9001)
+            .unwrap();
+        assert!(stacks.stack_map.get(&'3').unwrap().is_empty());
+        assert_eq!(
+            stacks.stack_map.get(&'1').unwrap(),
+            &vec!['Z', 'N', 'P', 'C', 'D'] // P/C/D keeps order
+        );
+    }
+
+    #[test]
+    fn it_parses_stacks_move_instruction() {
+        let ins = "move 15 from 7 to 9";
+        let (amount, (from_stack, to_stack)) = parse_instruction(ins, ins).unwrap();
+        assert_eq!(amount, 15);
+        assert_eq!(from_stack, '7');
+        assert_eq!(to_stack, '9');
+    }
+}
@@ -0,0 +1,83 @@
+use std::collections::HashSet;
+
+use crate::days::{Answer, Day, Params, Part, Puzzle, Year};
+use crate::error::SolveError;
+
+const PACKET_MARKER_SIZE: usize = 4;
+const MESSAGE_MARKER_SIZE: usize = 14;
+
+const EXAMPLE: &str = include_str!("../../examples/2022/day06/example.txt");
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    type Input = String;
+
+    fn year(&self) -> Year {
+        2022
+    }
+
+    fn day(&self) -> Day {
+        6
+    }
+
+    fn title(&self) -> &'static str {
+        "Tuning Trouble"
+    }
+
+    fn example(&self) -> &'static str {
+        EXAMPLE
+    }
+
+    fn parse(&self, data: &str) -> Result<Self::Input, SolveError> {
+        parse(data)
+    }
+
+    fn solve(
+        &self,
+        part: Part,
+        signal: &Self::Input,
+        _params: &Params,
+    ) -> Result<Answer, SolveError> {
+        match part {
+            1 => run(signal, PACKET_MARKER_SIZE).map(Answer::from),
+            _ => run(signal, MESSAGE_MARKER_SIZE).map(Answer::from),
+        }
+    }
+}
+
+fn parse(data: &str) -> Result<String, SolveError> {
+    Ok(parse_all(data, signal_parser)?)
+}
+
+use nom::{bytes::complete::take_while, combinator::map, error::context};
+
+use crate::parser::{parse_all, ParseResult};
+
+fn signal_parser(input: &str) -> ParseResult<'_, String> {
+    context(
+        "signal",
+        map(take_while(|c: char| c.is_ascii_lowercase()), String::from),
+    )(input)
+}
+
+fn run(data: &str, marker_size: usize) -> Result<usize, SolveError> {
+    for end in marker_size..=data.len() {
+        let maybe_marker = &data[end - marker_size..end];
+        if marker_offset(maybe_marker) {
+            return Ok(end);
+        }
+    }
+
+    Err(SolveError::unsolvable("didn't find marker start"))
+}
+
+fn marker_offset(maybe_marker: &str) -> bool {
+    let mut seen_chars = HashSet::new();
+    for c in maybe_marker.chars() {
+        if !seen_chars.insert(c) {
+            return false;
+        }
+    }
+    true
+}
@@ -1,45 +1,115 @@
 use std::cell::RefCell;
 use std::rc::Rc;
 
-pub fn part1(data: String) -> String {
-    let cmds = parse(&data);
-    let root = build_dirtree(&cmds);
-    let sizes = calc_dir_sizes(root);
-
-    sizes
+use crate::days::{Answer, Day, Param, Params, Part, Puzzle, Year};
+use crate::error::SolveError;
+
+const DISK_SIZE: u32 = 70_000_000;
+const UPDATE_SIZE: u32 = 30_000_000;
+
+const PART2_PARAMS: &[Param] = &[
+    Param {
+        name: "disk_size",
+        default: DISK_SIZE as i64,
+        help: "total size of the disk",
+    },
+    Param {
+        name: "update_size",
+        default: UPDATE_SIZE as i64,
+        help: "free space the update needs",
+    },
+];
+
+fn solve_part1(fs: &Filesystem) -> Result<Answer, SolveError> {
+    let sizes = calc_dir_sizes(Rc::clone(&fs.root));
+
+    Ok(sizes
         .iter()
         .filter(|size| **size <= 100_000)
         .sum::<u32>()
-        .to_string()
+        .into())
 }
 
-pub fn part2(data: String) -> String {
-    let cmds = parse(&data);
-    let root = build_dirtree(&cmds);
-    let sizes = calc_dir_sizes(Rc::clone(&root));
+fn solve_part2(fs: &Filesystem, total_size: u32, update_size: u32) -> Result<Answer, SolveError> {
+    let root = &fs.root;
+    let sizes = calc_dir_sizes(Rc::clone(root));
 
-    let total_size = 70000000_u32;
     let used_size = root.borrow().calc_size();
-    let remaining_size = total_size - used_size;
-    let update_size = 30000000_u32;
-    let required_size = update_size - remaining_size;
+    let remaining_size = total_size.checked_sub(used_size).ok_or_else(|| {
+        SolveError::Unsolvable(format!(
+            "used size {} is larger than the disk size {}",
+            used_size, total_size
+        ))
+    })?;
+    let required_size = update_size.saturating_sub(remaining_size);
 
-    dbg!(&sizes);
+    tracing::debug!(?sizes, used_size, required_size, "directory sizes");
 
     sizes
         .iter()
         .filter(|size| **size > required_size)
+        .copied()
         .min()
-        .unwrap()
-        .to_string()
+        .map(Answer::from)
+        .ok_or_else(|| SolveError::unsolvable("no directory is large enough to delete"))
 }
 
-fn parse(data: &str) -> Vec<Cmd> {
-    let (_rest, cmds) = cmds_parser(&data).unwrap();
-    cmds
+const EXAMPLE: &str = include_str!("../../examples/2022/day07/example.txt");
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    type Input = Filesystem;
+
+    fn year(&self) -> Year {
+        2022
+    }
+
+    fn day(&self) -> Day {
+        7
+    }
+
+    fn title(&self) -> &'static str {
+        "No Space Left On Device"
+    }
+
+    fn example(&self) -> &'static str {
+        EXAMPLE
+    }
+
+    fn params(&self, part: Part) -> &'static [Param] {
+        match part {
+            1 => &[],
+            _ => PART2_PARAMS,
+        }
+    }
+
+    fn parse(&self, data: &str) -> Result<Self::Input, SolveError> {
+        parse_filesystem(data)
+    }
+
+    fn solve(&self, part: Part, fs: &Self::Input, params: &Params) -> Result<Answer, SolveError> {
+        match part {
+            1 => solve_part1(fs),
+            _ => solve_part2(fs, params.get("disk_size")?, params.get("update_size")?),
+        }
+    }
 }
 
-fn build_dirtree(cmds: &Vec<Cmd>) -> Rc<RefCell<Dir>> {
+pub struct Filesystem {
+    root: Rc<RefCell<Dir>>,
+}
+
+fn parse_filesystem(data: &str) -> Result<Filesystem, SolveError> {
+    let root = build_dirtree(&parse(data)?)?;
+    Ok(Filesystem { root })
+}
+
+fn parse(data: &str) -> Result<Vec<Cmd>, SolveError> {
+    Ok(parse_all(data, cmds_parser)?)
+}
+
+fn build_dirtree(cmds: &Vec<Cmd>) -> Result<Rc<RefCell<Dir>>, SolveError> {
     let root = Rc::new(RefCell::new(Dir::root()));
     let mut current_path = vec![Rc::clone(&root)];
 
@@ -54,7 +124,7 @@ fn build_dirtree(cmds: &Vec<Cmd>) -> Rc<RefCell<Dir>> {
                         current_path.pop();
                     }
                     CdPath::Relative(rel_path) => {
-                        let dir = current_path.last().unwrap().borrow().cd(&rel_path).unwrap();
+                        let dir = current_dir(&current_path)?.borrow().cd(&rel_path)?;
                         current_path.push(Rc::clone(&dir));
                     }
                 },
@@ -62,13 +132,13 @@ fn build_dirtree(cmds: &Vec<Cmd>) -> Rc<RefCell<Dir>> {
                     for output in outputs {
                         match output {
                             LsOutput::DirOutput(dirname) => {
-                                let mut parent = current_path.last().unwrap().borrow_mut();
+                                let mut parent = current_dir(&current_path)?.borrow_mut();
                                 let child = Rc::new(RefCell::new(Dir::new(&dirname)));
-                                parent.mk_dir(child).unwrap();
+                                parent.mk_dir(child)?;
                             }
                             LsOutput::FileOutput(filename, size) => {
-                                let mut parent = current_path.last().unwrap().borrow_mut();
-                                parent.cp(File::new(&filename, *size)).unwrap();
+                                let mut parent = current_dir(&current_path)?.borrow_mut();
+                                parent.cp(File::new(&filename, *size))?;
                             }
                         }
                     }
@@ -76,7 +146,13 @@ fn build_dirtree(cmds: &Vec<Cmd>) -> Rc<RefCell<Dir>> {
             }
         }
     }
-    root
+    Ok(root)
+}
+
+fn current_dir(current_path: &[Rc<RefCell<Dir>>]) -> Result<&Rc<RefCell<Dir>>, SolveError> {
+    current_path
+        .last()
+        .ok_or_else(|| SolveError::unsolvable("cd .. above the root directory"))
 }
 
 fn calc_dir_sizes(root: Rc<RefCell<Dir>>) -> Vec<u32> {
@@ -113,6 +189,18 @@ struct NoSuchPathError(String);
 #[derive(Debug)]
 struct AlreadyExistsError(String);
 
+impl From<NoSuchPathError> for SolveError {
+    fn from(NoSuchPathError(path): NoSuchPathError) -> Self {
+        SolveError::Unsolvable(format!("no such directory: {}", path))
+    }
+}
+
+impl From<AlreadyExistsError> for SolveError {
+    fn from(AlreadyExistsError(name): AlreadyExistsError) -> Self {
+        SolveError::Unsolvable(format!("already exists: {}", name))
+    }
+}
+
 #[derive(Debug)]
 struct Dir {
     name: String,
@@ -214,30 +302,32 @@ use nom::{
     branch::alt,
     bytes::complete::tag,
     character::complete::{not_line_ending, u32},
-    combinator::map,
+    combinator::{cut, map},
+    error::context,
     multi::separated_list0,
     sequence::{preceded, separated_pair},
-    IResult,
 };
 
-fn cmds_parser(input: &str) -> IResult<&str, Vec<Cmd>> {
-    let mut parser = separated_list0(tag("\n"), cmd_parser);
+use crate::parser::{parse_all, ParseResult};
+
+fn cmds_parser(input: &str) -> ParseResult<'_, Vec<Cmd>> {
+    let mut parser = separated_list0(tag("\n"), cut(cmd_parser));
     parser(input)
 }
 
-fn cmd_parser(input: &str) -> IResult<&str, Cmd> {
-    let mut parser = preceded(tag("$ "), alt((cd_parser, ls_parser)));
+fn cmd_parser(input: &str) -> ParseResult<'_, Cmd> {
+    let mut parser = context("command", preceded(tag("$ "), alt((cd_parser, ls_parser))));
     parser(input)
 }
 
-fn cd_parser(input: &str) -> IResult<&str, Cmd> {
+fn cd_parser(input: &str) -> ParseResult<'_, Cmd> {
     let parser = preceded(tag("cd "), cd_path_parser);
     let mut cmd_parser = map(parser, |path| Cmd::Cd(path));
 
     cmd_parser(input)
 }
 
-fn cd_path_parser(input: &str) -> IResult<&str, CdPath> {
+fn cd_path_parser(input: &str) -> ParseResult<'_, CdPath> {
     let root_parser = map(tag("/"), |_| CdPath::Root);
     let parent_parser = map(tag(".."), |_| CdPath::Parent);
     let relative_parser = map(not_line_ending, |name: &str| {
@@ -248,7 +338,7 @@ fn cd_path_parser(input: &str) -> IResult<&str, CdPath> {
     parser(input)
 }
 
-fn ls_parser(input: &str) -> IResult<&str, Cmd> {
+fn ls_parser(input: &str) -> ParseResult<'_, Cmd> {
     let parser = preceded(
         tag("ls\n"),
         separated_list0(tag("\n"), alt((dir_parser, file_parser))),
@@ -259,14 +349,14 @@ fn ls_parser(input: &str) -> IResult<&str, Cmd> {
     cmd_parser(input)
 }
 
-fn dir_parser(input: &str) -> IResult<&str, LsOutput> {
+fn dir_parser(input: &str) -> ParseResult<'_, LsOutput> {
     let parser = preceded(tag("dir "), not_line_ending);
     let mut ls_output_parser = map(parser, |name: &str| LsOutput::DirOutput(name.to_string()));
 
     ls_output_parser(input)
 }
 
-fn file_parser(input: &str) -> IResult<&str, LsOutput> {
+fn file_parser(input: &str) -> ParseResult<'_, LsOutput> {
     let parser = separated_pair(u32, tag(" "), not_line_ending);
     let mut ls_output_parser = map(parser, |(size, name): (u32, &str)| {
         LsOutput::FileOutput(name.to_string(), size)
@@ -1,28 +1,79 @@
 use std::cmp;
 use std::str::FromStr;
 
+use crate::check;
 use crate::coord::{Coord, ICoord};
+use crate::days::{Answer, Day, Params, Part, Puzzle, Year};
+use crate::error::{ParseError, SolveError};
 
-pub fn part1(data: String) -> String {
-    let mut trees: Trees = data.parse().unwrap();
+fn solve_part1(trees: &Trees) -> Result<Answer, SolveError> {
+    let mut trees = trees.clone();
     trees.build_trees_visibility();
 
-    trees.iter().filter(|t| t.is_visible()).count().to_string()
+    Ok(trees.iter().filter(|t| t.is_visible()).count().into())
 }
 
-pub fn part2(data: String) -> String {
-    let mut trees: Trees = data.parse().unwrap();
+fn solve_part2(trees: &Trees) -> Result<Answer, SolveError> {
+    let mut trees = trees.clone();
     trees.build_trees_scenic_score();
 
     trees
         .iter()
         .map(|t| t.scenic_score)
         .max()
-        .unwrap()
-        .to_string()
+        .map(Answer::from)
+        .ok_or_else(|| SolveError::unsolvable("no trees found"))
 }
 
-struct Trees {
+const EXAMPLE: &str = include_str!("../../examples/2022/day08/example.txt");
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    type Input = Trees;
+
+    fn year(&self) -> Year {
+        2022
+    }
+
+    fn day(&self) -> Day {
+        8
+    }
+
+    fn title(&self) -> &'static str {
+        "Treetop Tree House"
+    }
+
+    fn example(&self) -> &'static str {
+        EXAMPLE
+    }
+
+    // parsing stops at the first row of the wrong width, so report them all
+    fn check(&self, data: &str) -> Vec<ParseError> {
+        let mut problems = check::common(data);
+        problems.extend(check::row_widths(data));
+        problems
+    }
+
+    fn parse(&self, data: &str) -> Result<Self::Input, SolveError> {
+        Ok(data.parse()?)
+    }
+
+    fn solve(
+        &self,
+        part: Part,
+        trees: &Self::Input,
+        _params: &Params,
+    ) -> Result<Answer, SolveError> {
+        match part {
+            1 => solve_part1(trees),
+            _ => solve_part2(trees),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Trees {
     trees: Vec<Tree>,
     width: u32,
     height: u32,
@@ -40,7 +91,12 @@ impl Trees {
     }
 
     fn append_row(&mut self, mut row: Vec<Tree>) -> Result<(), InconsistentRowSizeError> {
-        if self.width == 0 {
+        if row.is_empty() {
+            return Err(InconsistentRowSizeError(
+                "Expected a row of trees".to_string(),
+            ));
+        }
+        if self.height == 0 {
             // first row, set width and expect subsequent to be equal
             self.width = row.len() as u32;
         } else {
@@ -174,7 +230,13 @@ impl Trees {
 }
 
 #[derive(Debug)]
-struct ParseTreesError(String);
+pub struct ParseTreesError(ParseError);
+
+impl From<ParseTreesError> for SolveError {
+    fn from(ParseTreesError(e): ParseTreesError) -> Self {
+        SolveError::Parse(e)
+    }
+}
 
 impl FromStr for Trees {
     type Err = ParseTreesError;
@@ -183,13 +245,15 @@ impl FromStr for Trees {
 
         for (y, line) in s.lines().enumerate() {
             let mut tree_row: Vec<Tree> = vec![];
-            for (x, c) in line.chars().enumerate() {
+            for (x, (i, c)) in line.char_indices().enumerate() {
                 let h = match c {
                     c @ '0'..='9' => c as i8 - '0' as i8,
                     e => {
-                        return Err(ParseTreesError(
-                            format!("bad height found: {e}").to_string(),
-                        ))
+                        return Err(ParseTreesError(ParseError::at(
+                            s,
+                            &line[i..],
+                            format!("bad height found: {e:?}"),
+                        )))
                     }
                 };
                 let tree = Tree::new(h, Coord::new(x as u32, y as u32));
@@ -197,7 +261,9 @@ impl FromStr for Trees {
             }
             trees
                 .append_row(tree_row)
-                .or_else(|InconsistentRowSizeError(msg)| Err(ParseTreesError(msg)))?;
+                .or_else(|InconsistentRowSizeError(msg)| {
+                    Err(ParseTreesError(ParseError::at(s, line, msg)))
+                })?;
         }
 
         Ok(trees)
@@ -213,9 +279,9 @@ impl IntoIterator for Trees {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 #[allow(dead_code)]
-struct Tree {
+pub struct Tree {
     h: Height,
     coord: Coord<u32>,
     vis: Visibilities,
@@ -245,7 +311,7 @@ impl Tree {
 type Height = i8;
 type Vis = i8;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct Visibilities {
     n: Vis,
     e: Vis,
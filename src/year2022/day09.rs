@@ -1,30 +1,91 @@
 use std::collections::HashSet;
 
 use nom::{
-    branch::alt, bytes::complete::tag, character::complete::u32, combinator::map,
-    multi::separated_list0, sequence::separated_pair, IResult,
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::u32,
+    combinator::{cut, map},
+    error::context,
+    multi::separated_list0,
+    sequence::separated_pair,
 };
 
 use crate::coord::{Coord, Direction, ICoord};
+use crate::days::{Answer, Day, Param, Params, Part, Puzzle, Year};
+use crate::error::SolveError;
+use crate::parser::{parse_all, ParseResult};
 
-pub fn part1(data: String) -> String {
-    run(data, 2).to_string()
-}
+const SHORT_ROPE_LENGTH: usize = 2;
+const LONG_ROPE_LENGTH: usize = 10;
+
+const PART1_PARAMS: &[Param] = &[Param {
+    name: "knots",
+    default: SHORT_ROPE_LENGTH as i64,
+    help: "number of knots in the rope, including the head",
+}];
+const PART2_PARAMS: &[Param] = &[Param {
+    name: "knots",
+    default: LONG_ROPE_LENGTH as i64,
+    help: "number of knots in the rope, including the head",
+}];
+
+const EXAMPLE: &str = include_str!("../../examples/2022/day09/example.txt");
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    type Input = Vec<Instruction>;
+
+    fn year(&self) -> Year {
+        2022
+    }
+
+    fn day(&self) -> Day {
+        9
+    }
+
+    fn title(&self) -> &'static str {
+        "Rope Bridge"
+    }
+
+    fn example(&self) -> &'static str {
+        EXAMPLE
+    }
+
+    fn params(&self, part: Part) -> &'static [Param] {
+        match part {
+            1 => PART1_PARAMS,
+            _ => PART2_PARAMS,
+        }
+    }
 
-pub fn part2(data: String) -> String {
-    run(data, 10).to_string()
+    fn parse(&self, data: &str) -> Result<Self::Input, SolveError> {
+        parse(data)
+    }
+
+    fn solve(
+        &self,
+        _part: Part,
+        instructions: &Self::Input,
+        params: &Params,
+    ) -> Result<Answer, SolveError> {
+        run(instructions, params.get("knots")?).map(Answer::from)
+    }
 }
 
-fn run(data: String, rope_length: usize) -> usize {
+fn run(instructions: &[Instruction], rope_length: usize) -> Result<usize, SolveError> {
+    if rope_length == 0 {
+        return Err(SolveError::unsolvable("the rope needs at least one knot"));
+    }
     let mut rope = Rope::new(rope_length);
     let mut tail_visits: HashSet<Coord<i32>> = HashSet::new();
-    for instruction in parse(&data) {
+    for instruction in instructions {
         for _ in 0..instruction.size {
             rope.mv_head(&instruction.dir);
             tail_visits.insert(rope.tail().clone());
         }
     }
-    tail_visits.len()
+    Ok(tail_visits.len())
 }
 
 struct Rope {
@@ -87,37 +148,37 @@ fn chase(coord: &Coord<i32>, other: &Coord<i32>) -> Option<Coord<i32>> {
 }
 
 #[derive(Debug, PartialEq)]
-struct Instruction {
+pub struct Instruction {
     dir: Direction,
     size: MovementSize,
 }
 
 type MovementSize = u32;
 
-fn parse(input: &str) -> Vec<Instruction> {
-    let (_rest, instructions) = instructions_parser(input).unwrap();
-    instructions
+fn parse(input: &str) -> Result<Vec<Instruction>, SolveError> {
+    Ok(parse_all(input, instructions_parser)?)
 }
 
-fn instructions_parser(input: &str) -> IResult<&str, Vec<Instruction>> {
-    let mut parser = separated_list0(tag("\n"), instruction_parser);
+fn instructions_parser(input: &str) -> ParseResult<'_, Vec<Instruction>> {
+    let mut parser = separated_list0(tag("\n"), cut(instruction_parser));
     parser(input)
 }
 
-fn instruction_parser(input: &str) -> IResult<&str, Instruction> {
-    let mut parser = map(separated_pair(dir_parser, tag(" "), u32), |(d, s)| {
+fn instruction_parser(input: &str) -> ParseResult<'_, Instruction> {
+    let parser = map(separated_pair(dir_parser, tag(" "), u32), |(d, s)| {
         Instruction { dir: d, size: s }
     });
+    let mut parser = context("instruction", parser);
     parser(input)
 }
 
-fn dir_parser(input: &str) -> IResult<&str, Direction> {
+fn dir_parser(input: &str) -> ParseResult<'_, Direction> {
     use Direction::*;
     let l = map(tag("L"), |_| L);
     let r = map(tag("R"), |_| R);
     let u = map(tag("U"), |_| U);
     let d = map(tag("D"), |_| D);
-    let mut parser = alt((l, r, u, d));
+    let mut parser = context("direction", alt((l, r, u, d)));
     parser(input)
 }
 
@@ -145,30 +206,14 @@ mod tests {
             ("", Instruction { dir: D, size: 11 })
         );
 
-        assert_eq!(
-            instruction_parser("").unwrap_err().to_string(),
-            "Parsing Error: Error { input: \"\", code: Tag }"
-        );
-        assert_eq!(
-            instruction_parser("R").unwrap_err().to_string(),
-            "Parsing Error: Error { input: \"\", code: Tag }"
-        );
-        assert_eq!(
-            instruction_parser("R ").unwrap_err().to_string(),
-            "Parsing Error: Error { input: \"\", code: Digit }"
-        );
-        assert_eq!(
-            instruction_parser("R -123").unwrap_err().to_string(),
-            "Parsing Error: Error { input: \"-123\", code: Digit }"
-        );
-        assert_eq!(
-            instruction_parser("R foobar").unwrap_err().to_string(),
-            "Parsing Error: Error { input: \"foobar\", code: Digit }"
-        );
-        assert_eq!(
-            instruction_parser("Z 12").unwrap_err().to_string(),
-            "Parsing Error: Error { input: \"Z 12\", code: Tag }"
-        );
+        let error = |s| parse_all(s, instruction_parser).unwrap_err();
+        assert_eq!(error("").message, "unexpected end of input in direction");
+        assert_eq!(error("R").message, "unexpected end of input in instruction");
+        assert_eq!(error("R ").message, "expected a number in instruction");
+        assert_eq!(error("R -123").message, "expected a number in instruction");
+        assert_eq!(error("R -123").location.unwrap().column, 3);
+        assert_eq!(error("Z 12").message, "unexpected \"Z\" in direction");
+        assert_eq!(error("R 4 4").message, "unexpected ' '");
     }
 
     #[test]
@@ -0,0 +1,131 @@
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::i32,
+    combinator::{cut, map},
+    error::context,
+    multi::separated_list0,
+    sequence::separated_pair,
+};
+
+use crate::answer::Grid;
+use crate::days::{Answer, Day, Params, Part, Puzzle, Year};
+use crate::error::SolveError;
+use crate::parser::{parse_all, ParseResult};
+
+fn solve_part1(ops: &[Op]) -> Result<Answer, SolveError> {
+    let mut signal_strength = Some(0_i32);
+    run(ops, |i, x_reg| {
+        let cycle = i as i32 + 1;
+        if (cycle - 20) % 40 == 0 {
+            signal_strength = signal_strength
+                .and_then(|strength| strength.checked_add(cycle.checked_mul(x_reg)?));
+        }
+    })?;
+    signal_strength
+        .map(Answer::from)
+        .ok_or_else(|| SolveError::unsolvable("signal strength overflowed"))
+}
+
+const CRT_WIDTH: usize = 40;
+fn solve_part2(ops: &[Op]) -> Result<Answer, SolveError> {
+    let mut rows = vec![];
+    run(ops, |i, x_reg| {
+        let pixel = i % CRT_WIDTH;
+        if pixel == 0 {
+            rows.push(Vec::with_capacity(CRT_WIDTH));
+        }
+        let lit = x_reg.abs_diff(pixel as i32) <= 1;
+        rows.last_mut().unwrap().push(lit);
+    })?;
+    Ok(Grid::new(rows).into())
+}
+
+const EXAMPLE: &str = include_str!("../../examples/2022/day10/example.txt");
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    type Input = Vec<Op>;
+
+    fn year(&self) -> Year {
+        2022
+    }
+
+    fn day(&self) -> Day {
+        10
+    }
+
+    fn title(&self) -> &'static str {
+        "Cathode-Ray Tube"
+    }
+
+    fn example(&self) -> &'static str {
+        EXAMPLE
+    }
+
+    fn parse(&self, data: &str) -> Result<Self::Input, SolveError> {
+        parse(data)
+    }
+
+    fn solve(&self, part: Part, ops: &Self::Input, _params: &Params) -> Result<Answer, SolveError> {
+        match part {
+            1 => solve_part1(ops),
+            _ => solve_part2(ops),
+        }
+    }
+}
+
+type Idx = usize;
+type Reg = i32;
+
+fn run<F>(ops: &[Op], mut f: F) -> Result<(), SolveError>
+where
+    F: FnMut(Idx, Reg) -> (),
+{
+    let expanded_ops = ops.iter().flat_map(|op| match op {
+        Op::Noop => vec![Op::Noop],
+        ax @ Op::Addx(_) => vec![Op::Noop, *ax],
+    });
+
+    let mut x_reg = 1_i32;
+    for (i, op) in expanded_ops.enumerate() {
+        f(i, x_reg);
+        match op {
+            Op::Noop => (),
+            Op::Addx(amount) => {
+                x_reg = x_reg
+                    .checked_add(amount)
+                    .ok_or_else(|| SolveError::unsolvable("register X overflowed"))?
+            }
+        }
+    }
+    Ok(())
+}
+
+#[derive(Debug, Copy, Clone)]
+pub enum Op {
+    Noop,
+    Addx(i32),
+}
+
+fn parse(input: &str) -> Result<Vec<Op>, SolveError> {
+    Ok(parse_all(input, ops_parser)?)
+}
+
+fn ops_parser(input: &str) -> ParseResult<'_, Vec<Op>> {
+    separated_list0(tag("\n"), cut(op_parser))(input)
+}
+
+fn op_parser(input: &str) -> ParseResult<'_, Op> {
+    context("instruction", alt((noop_parser, addx_parser)))(input)
+}
+
+fn noop_parser(input: &str) -> ParseResult<'_, Op> {
+    map(tag("noop"), |_| Op::Noop)(input)
+}
+
+fn addx_parser(input: &str) -> ParseResult<'_, Op> {
+    let p = separated_pair(tag("addx"), tag(" "), i32);
+    map(p, |(_, amount)| Op::Addx(amount))(input)
+}
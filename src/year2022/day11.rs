@@ -2,40 +2,123 @@ use nom::{
     branch::alt,
     bytes::complete::tag,
     character::complete::{u32, u64},
-    combinator::map,
+    combinator::{cut, map},
+    error::context,
     multi::separated_list0,
     sequence::{preceded, separated_pair, terminated, tuple},
-    IResult,
 };
 use std::cell::RefCell;
 use std::collections::VecDeque;
 use std::rc::Rc;
 
-pub fn part1(data: String) -> String {
-    let rounds = 20;
-    let boredom_factor = 3;
-    run(&data, rounds, boredom_factor).to_string()
+use crate::check;
+use crate::days::{Answer, Day, Param, Params, Part, Puzzle, Year};
+use crate::error::{ParseError, SolveError};
+use crate::parser::{parse_all, ParseResult};
+
+const PART1_ROUNDS: u32 = 20;
+const PART1_BOREDOM_FACTOR: u32 = 3;
+const PART2_ROUNDS: u32 = 10_000;
+const PART2_BOREDOM_FACTOR: u32 = 1;
+
+const PART1_PARAMS: &[Param] = &monkey_params(PART1_ROUNDS, PART1_BOREDOM_FACTOR);
+const PART2_PARAMS: &[Param] = &monkey_params(PART2_ROUNDS, PART2_BOREDOM_FACTOR);
+
+const fn monkey_params(rounds: u32, boredom_factor: u32) -> [Param; 2] {
+    [
+        Param {
+            name: "rounds",
+            default: rounds as i64,
+            help: "number of rounds of monkey business",
+        },
+        Param {
+            name: "boredom_factor",
+            default: boredom_factor as i64,
+            help: "how much worry levels are divided by after each inspection",
+        },
+    ]
 }
 
-pub fn part2(data: String) -> String {
-    let rounds = 10_000;
-    let boredom_factor = 1;
-    run(&data, rounds, boredom_factor).to_string()
+const EXAMPLE: &str = include_str!("../../examples/2022/day11/example.txt");
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    type Input = Vec<Monkey>;
+
+    fn year(&self) -> Year {
+        2022
+    }
+
+    fn day(&self) -> Day {
+        11
+    }
+
+    fn title(&self) -> &'static str {
+        "Monkey in the Middle"
+    }
+
+    fn example(&self) -> &'static str {
+        EXAMPLE
+    }
+
+    fn params(&self, part: Part) -> &'static [Param] {
+        match part {
+            1 => PART1_PARAMS,
+            _ => PART2_PARAMS,
+        }
+    }
+
+    fn check(&self, data: &str) -> Vec<ParseError> {
+        let mut problems = check::common(data);
+        problems.extend(check_monkeys(data));
+        problems
+    }
+
+    fn parse(&self, data: &str) -> Result<Self::Input, SolveError> {
+        parse_monkeys(data)
+    }
+
+    fn solve(
+        &self,
+        _part: Part,
+        monkeys: &Self::Input,
+        params: &Params,
+    ) -> Result<Answer, SolveError> {
+        let rounds = params.get("rounds")?;
+        let boredom_factor = params.get("boredom_factor")?;
+        run(monkeys, rounds, boredom_factor).map(Answer::from)
+    }
 }
 
-fn run(data: &str, rounds: u32, boredom_factor: u32) -> u64 {
-    let monkeys = parse(&data);
+fn run(monkeys: &[Monkey], rounds: u32, boredom_factor: u32) -> Result<u64, SolveError> {
+    if boredom_factor == 0 {
+        return Err(SolveError::unsolvable("the boredom factor can't be zero"));
+    }
+    let monkeys = monkeys
+        .iter()
+        .map(|m| Rc::new(RefCell::new(m.clone())))
+        .collect::<Vec<Rc<RefCell<Monkey>>>>();
+
     let lcm: u32 = monkeys
         .iter()
         .map(|m| m.borrow().test.divisible_by)
         .product();
 
-    for _i in 1..=rounds {
+    for round in 1..=rounds {
+        let _span = tracing::trace_span!("round", round).entered();
         for monkey in monkeys.iter() {
             monkey
                 .borrow_mut()
                 .inspect_and_throw_items(&monkeys, boredom_factor, lcm);
         }
+        tracing::trace!(
+            inspections = ?monkeys
+                .iter()
+                .map(|m| m.borrow().inspection_count)
+                .collect::<Vec<_>>(),
+            "round complete"
+        );
     }
 
     let mut inspection_counts = monkeys
@@ -44,11 +127,26 @@ fn run(data: &str, rounds: u32, boredom_factor: u32) -> u64 {
         .collect::<Vec<u64>>();
     inspection_counts.sort();
     inspection_counts.reverse();
-    inspection_counts[0..=1].iter().product::<u64>()
+    Ok(inspection_counts[0..=1].iter().product::<u64>())
+}
+
+fn check_throws(monkeys: &[Rc<RefCell<Monkey>>]) -> Result<(), SolveError> {
+    for (id, monkey) in monkeys.iter().enumerate() {
+        let test = &monkey.borrow().test;
+        for throw_to in [test.true_throw, test.false_throw] {
+            if throw_to == id || throw_to >= monkeys.len() {
+                return Err(SolveError::Unsolvable(format!(
+                    "monkey {} can't throw to monkey {}",
+                    id, throw_to
+                )));
+            }
+        }
+    }
+    Ok(())
 }
 
-#[derive(Debug, PartialEq)]
-struct Monkey {
+#[derive(Debug, PartialEq, Clone)]
+pub struct Monkey {
     items: VecDeque<Item>,
     op: Op,
     test: Test,
@@ -96,7 +194,7 @@ type MonkeyId = usize;
 
 type Item = u64;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 enum Op {
     Mult(u32),
     Plus(u32),
@@ -114,7 +212,7 @@ impl Op {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 struct Test {
     divisible_by: u32,
     true_throw: MonkeyId,
@@ -139,15 +237,67 @@ impl Test {
     }
 }
 
-fn parse(input: &str) -> Vec<Rc<RefCell<Monkey>>> {
-    let (_rest, monkeys) = monkeys_parser(input).unwrap();
-    monkeys
+// Monkeys are identified by their position, so their ids must count up from 0, and each must
+// throw to another monkey that exists. The parser ignores the ids.
+fn check_monkeys(data: &str) -> Vec<ParseError> {
+    let monkey_count = data
+        .lines()
+        .filter(|line| line.starts_with("Monkey "))
+        .count();
+    let mut problems = vec![];
+    let mut current = None;
+    for line in data.lines() {
+        if let Some(id) = line
+            .strip_prefix("Monkey ")
+            .and_then(|rest| rest.trim_end().strip_suffix(':'))
+        {
+            let expected = current.map_or(0, |id| id + 1);
+            current = Some(expected);
+            if id.parse() != Ok(expected) {
+                problems.push(ParseError::at(
+                    data,
+                    id,
+                    format!("monkey {} should be monkey {}", id, expected),
+                ));
+            }
+        } else if let Some((_, target)) = line.split_once("throw to monkey ") {
+            let Ok(to) = target.trim_end().parse::<usize>() else {
+                continue;
+            };
+            if Some(to) == current {
+                problems.push(ParseError::at(data, target, "monkey throws to itself"));
+            } else if to >= monkey_count {
+                problems.push(ParseError::at(
+                    data,
+                    target,
+                    format!(
+                        "throws to monkey {}, but there are only {} monkeys",
+                        to, monkey_count
+                    ),
+                ));
+            }
+        }
+    }
+    problems
+}
+
+fn parse_monkeys(input: &str) -> Result<Vec<Monkey>, SolveError> {
+    let monkeys = parse(input)?;
+    check_throws(&monkeys)?;
+    if monkeys.len() < 2 {
+        return Err(SolveError::unsolvable("need at least two monkeys"));
+    }
+    Ok(monkeys.iter().map(|m| m.borrow().clone()).collect())
+}
+
+fn parse(input: &str) -> Result<Vec<Rc<RefCell<Monkey>>>, SolveError> {
+    Ok(parse_all(input, monkeys_parser)?)
 }
 
-fn monkeys_parser(input: &str) -> IResult<&str, Vec<Rc<RefCell<Monkey>>>> {
-    separated_list0(tag("\n\n"), monkey_parser)(input)
+fn monkeys_parser(input: &str) -> ParseResult<'_, Vec<Rc<RefCell<Monkey>>>> {
+    separated_list0(tag("\n\n"), cut(monkey_parser))(input)
 }
-fn monkey_parser(input: &str) -> IResult<&str, Rc<RefCell<Monkey>>> {
+fn monkey_parser(input: &str) -> ParseResult<'_, Rc<RefCell<Monkey>>> {
     let header = preceded(tag("Monkey "), terminated(u32, tag(":\n")));
     let p = preceded(
         header,
@@ -157,41 +307,43 @@ fn monkey_parser(input: &str) -> IResult<&str, Rc<RefCell<Monkey>>> {
             test_parser,
         )),
     );
-    map(p, |(items, op, test)| {
+    let p = map(p, |(items, op, test)| {
         Rc::new(RefCell::new(Monkey::new(items.into(), op, test)))
-    })(input)
+    });
+    context("monkey", p)(input)
 }
 
-fn items_parser(input: &str) -> IResult<&str, Vec<Item>> {
-    let mut p = preceded(tag("  Starting items: "), separated_list0(tag(", "), u64));
-    p(input)
+fn items_parser(input: &str) -> ParseResult<'_, Vec<Item>> {
+    let p = preceded(tag("  Starting items: "), separated_list0(tag(", "), u64));
+    context("starting items", p)(input)
 }
 
-fn operation_parser(input: &str) -> IResult<&str, Op> {
+fn operation_parser(input: &str) -> ParseResult<'_, Op> {
     let mult = map(preceded(tag("* "), u32), |num| Op::Mult(num));
     let plus = map(preceded(tag("+ "), u32), |num| Op::Plus(num));
     let sq = map(tag("* old"), |_| Op::Sq);
-    preceded(tag("  Operation: new = old "), alt((mult, plus, sq)))(input)
+    let p = preceded(tag("  Operation: new = old "), alt((mult, plus, sq)));
+    context("operation", p)(input)
 }
 
-fn test_parser(input: &str) -> IResult<&str, Test> {
+fn test_parser(input: &str) -> ParseResult<'_, Test> {
     let check = terminated(test_check_parser, tag("\n"));
     let clauses = separated_pair(
         test_clause_parser(true),
         tag("\n"),
         test_clause_parser(false),
     );
-    let mut p = map(tuple((check, clauses)), |(div, (t, f))| {
+    let p = map(tuple((check, clauses)), |(div, (t, f))| {
         Test::new(div, t, f)
     });
-    p(input)
+    context("test", p)(input)
 }
 
-fn test_check_parser(input: &str) -> IResult<&str, u32> {
+fn test_check_parser(input: &str) -> ParseResult<'_, u32> {
     preceded(tag("  Test: divisible by "), u32)(input)
 }
 
-fn test_clause_parser(test_result: bool) -> impl FnMut(&str) -> IResult<&str, MonkeyId> {
+fn test_clause_parser(test_result: bool) -> impl FnMut(&str) -> ParseResult<'_, MonkeyId> {
     move |input| {
         let bool_parser = if test_result {
             tag("true")
@@ -249,7 +401,7 @@ Monkey 3:
             Monkey::new(vec![79, 60, 97].into(), Op::Sq, Test::new(13, 1, 3)),
             Monkey::new(vec![74].into(), Op::Plus(3), Test::new(17, 0, 1)),
         ];
-        let parsed = parse(DATA);
+        let parsed = parse(DATA).unwrap();
         assert!(parsed[0].borrow().eq(&expected[0]));
         assert!(parsed[1].borrow().eq(&expected[1]));
         assert!(parsed[2].borrow().eq(&expected[2]));
@@ -0,0 +1,335 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::Debug;
+
+use crate::check;
+use crate::coord::{Coord, ICoord};
+use crate::days::{Answer, Day, Params, Part, Puzzle, Variant, Year};
+use crate::error::{ParseError, SolveError};
+use crate::graph::Graph;
+
+fn solve_part1(terrain: &Terrain) -> Result<Answer, SolveError> {
+    let Terrain {
+        heightmap,
+        start,
+        end,
+    } = terrain;
+
+    // paths from start -> every other square
+    let graph = build_graph(heightmap, |height, neighbour_height| {
+        height + 1 >= neighbour_height
+    });
+    let shortest_paths = graph.shortest_paths_from(start);
+
+    shortest_paths
+        .get(end)
+        .map(|&steps| Answer::from(steps))
+        .ok_or_else(|| SolveError::unsolvable("no path to the end"))
+}
+
+fn solve_part2(terrain: &Terrain) -> Result<Answer, SolveError> {
+    let Terrain { heightmap, end, .. } = terrain;
+
+    // paths from end -> every other square
+    let reverse_graph = build_graph(heightmap, |height, neighbour_height| {
+        height <= neighbour_height + 1
+    });
+    let reverse_shortest_paths = reverse_graph.shortest_paths_from(end);
+
+    heightmap
+        .iter()
+        .filter(|(_, h)| **h == START_HEIGHT)
+        .filter_map(|(c, _)| reverse_shortest_paths.get(c).copied())
+        .min()
+        .map(Answer::from)
+        .ok_or_else(|| unreachable_from_start(heightmap))
+}
+
+// Every step costs the same, so the nearest square found breadth first is the closest.
+fn solve_part1_bfs(terrain: &Terrain) -> Result<Answer, SolveError> {
+    let Terrain {
+        heightmap,
+        start,
+        end,
+    } = terrain;
+
+    steps_to_nearest(
+        heightmap,
+        *start,
+        |height, neighbour_height| height + 1 >= neighbour_height,
+        |coord| coord == end,
+    )
+    .map(Answer::from)
+    .ok_or_else(|| SolveError::unsolvable("no path to the end"))
+}
+
+fn solve_part2_bfs(terrain: &Terrain) -> Result<Answer, SolveError> {
+    let Terrain { heightmap, end, .. } = terrain;
+
+    steps_to_nearest(
+        heightmap,
+        *end,
+        |height, neighbour_height| height <= neighbour_height + 1,
+        |coord| heightmap[coord] == START_HEIGHT,
+    )
+    .map(Answer::from)
+    .ok_or_else(|| unreachable_from_start(heightmap))
+}
+
+// Why no starting square has a path to the end in part 2.
+fn unreachable_from_start(hm: &HeightMap) -> SolveError {
+    if hm.values().any(|&h| h == START_HEIGHT) {
+        SolveError::unsolvable("the end is unreachable from every starting square")
+    } else {
+        SolveError::unsolvable("no starting squares found")
+    }
+}
+
+fn steps_to_nearest(
+    hm: &HeightMap,
+    from: Coord<u32>,
+    neighbour_check: impl Fn(Height, NeighbourHeight) -> bool,
+    is_goal: impl Fn(&Coord<u32>) -> bool,
+) -> Option<EdgeWeight> {
+    let mut seen = HashSet::from([from]);
+    let mut queue = VecDeque::from([(from, 0)]);
+    while let Some((coord, steps)) = queue.pop_front() {
+        if is_goal(&coord) {
+            return Some(steps);
+        }
+        for (n_coord, n_h) in neighbours(hm, coord) {
+            if neighbour_check(hm[&coord], n_h) && seen.insert(n_coord) {
+                queue.push_back((n_coord, steps + STEP_WEIGHT));
+            }
+        }
+    }
+    None
+}
+
+const EXAMPLE: &str = include_str!("../../examples/2022/day12/example.txt");
+
+const VARIANTS: &[Variant] = &[Variant {
+    name: "bfs",
+    help: "breadth first search, as every step costs the same, rather than Dijkstra's algorithm",
+}];
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    type Input = Terrain;
+
+    fn year(&self) -> Year {
+        2022
+    }
+
+    fn day(&self) -> Day {
+        12
+    }
+
+    fn title(&self) -> &'static str {
+        "Hill Climbing Algorithm"
+    }
+
+    fn example(&self) -> &'static str {
+        EXAMPLE
+    }
+
+    fn check(&self, data: &str) -> Vec<ParseError> {
+        let mut problems = check::common(data);
+        problems.extend(check::row_widths(data));
+        problems.extend(check_marker(data, 'S', "start"));
+        problems.extend(check_marker(data, 'E', "end"));
+        problems
+    }
+
+    fn variants(&self, _part: Part) -> &'static [Variant] {
+        VARIANTS
+    }
+
+    fn parse(&self, data: &str) -> Result<Self::Input, SolveError> {
+        parse_terrain(data)
+    }
+
+    fn solve(
+        &self,
+        part: Part,
+        terrain: &Self::Input,
+        params: &Params,
+    ) -> Result<Answer, SolveError> {
+        match (part, params.variant()) {
+            (1, Some("bfs")) => solve_part1_bfs(terrain),
+            (1, _) => solve_part1(terrain),
+            (_, Some("bfs")) => solve_part2_bfs(terrain),
+            _ => solve_part2(terrain),
+        }
+    }
+}
+
+const START_HEIGHT: u8 = 0;
+const END_HEIGHT: u8 = 25;
+const STEP_WEIGHT: u32 = 1;
+
+pub struct Terrain {
+    heightmap: HeightMap,
+    start: Coord<u32>,
+    end: Coord<u32>,
+}
+
+fn parse_terrain(data: &str) -> Result<Terrain, SolveError> {
+    let (heightmap, start, end) = build_heightmap(parse(data)?)?;
+    Ok(Terrain {
+        heightmap,
+        start,
+        end,
+    })
+}
+
+// There must be exactly one of `marker` in the heightmap `data`.
+fn check_marker(data: &str, marker: char, name: &str) -> Vec<ParseError> {
+    let mut found = data.match_indices(marker).map(|(i, _)| &data[i..]);
+    let Some(first) = found.next() else {
+        return vec![ParseError::new(format!("no {} {:?} found", name, marker))];
+    };
+    let first_line = data[..data.len() - first.len()].matches('\n').count() + 1;
+    found
+        .map(|at| {
+            ParseError::at(
+                data,
+                at,
+                format!(
+                    "another {} {:?}, the first is on line {}",
+                    name, marker, first_line
+                ),
+            )
+        })
+        .collect()
+}
+
+fn build_heightmap(
+    parsed: Vec<Vec<ParsedHeight>>,
+) -> Result<(HeightMap, Coord<u32>, Coord<u32>), SolveError> {
+    let mut heightmap = HeightMap::new();
+    let mut start: Option<Coord<u32>> = None;
+    let mut end: Option<Coord<u32>> = None;
+
+    for (y, row) in parsed.iter().enumerate() {
+        for (x, parsed_h) in row.iter().enumerate() {
+            let coord = Coord::new(x as u32, y as u32);
+            let h = match parsed_h {
+                ParsedHeight::Start => match start {
+                    Some(_) => return Err(SolveError::parse("start already set")),
+                    None => {
+                        start = Some(coord);
+                        START_HEIGHT
+                    }
+                },
+                ParsedHeight::End => match end {
+                    Some(_) => return Err(SolveError::parse("end already set")),
+                    None => {
+                        end = Some(coord);
+                        END_HEIGHT
+                    }
+                },
+                ParsedHeight::Elevation(h) => *h,
+            };
+            heightmap.insert(coord, h);
+        }
+    }
+    match (start, end) {
+        (Some(start), Some(end)) => Ok((heightmap, start, end)),
+        (None, _) => Err(SolveError::parse("start not found")),
+        (_, None) => Err(SolveError::parse("end not found")),
+    }
+}
+
+fn build_graph(
+    hm: &HeightMap,
+    neighbour_check: impl Fn(Height, NeighbourHeight) -> bool,
+) -> Graph<Coord<u32>, EdgeWeight> {
+    let mut g: Graph<Coord<u32>, EdgeWeight> = Graph::new();
+    for (coord, h) in hm.iter() {
+        g.push_vertex(*coord);
+        for (n_coord, n_h) in neighbours(hm, *coord).iter() {
+            if neighbour_check(*h, *n_h) {
+                g.push_edge(*coord, *n_coord, STEP_WEIGHT);
+            }
+        }
+    }
+    g
+}
+
+type HeightMap = HashMap<Coord<u32>, Height>;
+
+fn neighbours(hm: &HeightMap, coord: Coord<u32>) -> Vec<(Coord<u32>, NeighbourHeight)> {
+    let x = coord.x();
+    let y = coord.y();
+
+    let mut ns = vec![];
+    ns.push(Coord::new(x, y + 1));
+    ns.push(Coord::new(x + 1, y));
+    // guard against panic due to subtracing from 0 for usize
+    if y > 0 {
+        ns.push(Coord::new(x, y - 1))
+    };
+    if x > 0 {
+        ns.push(Coord::new(x - 1, y))
+    };
+
+    ns.iter()
+        .filter_map(|c| hm.get(c).map(|h| (*c, *h)))
+        .collect()
+}
+
+type Height = u8;
+type NeighbourHeight = u8;
+
+type EdgeWeight = u32;
+
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::satisfy,
+    combinator::{map, value},
+    multi::{many1, separated_list1},
+};
+
+use crate::parser::{parse_all, ParseResult};
+
+#[derive(Clone, Debug)]
+pub enum ParsedHeight {
+    Start,
+    End,
+    Elevation(u8),
+}
+
+const ASCII_OFFSET: u8 = 97;
+
+pub fn parse(s: &str) -> Result<Vec<Vec<ParsedHeight>>, SolveError> {
+    Ok(parse_all(s, heightmap)?)
+}
+fn heightmap(s: &str) -> ParseResult<'_, Vec<Vec<ParsedHeight>>> {
+    separated_list1(tag("\n"), heightmap_row)(s)
+}
+
+fn heightmap_row(s: &str) -> ParseResult<'_, Vec<ParsedHeight>> {
+    many1(height)(s)
+}
+
+fn height(s: &str) -> ParseResult<'_, ParsedHeight> {
+    alt((start, end, elevation))(s)
+}
+
+fn start(s: &str) -> ParseResult<'_, ParsedHeight> {
+    let mut p = value(ParsedHeight::Start, tag("S"));
+    p(s)
+}
+
+fn end(s: &str) -> ParseResult<'_, ParsedHeight> {
+    let mut p = value(ParsedHeight::End, tag("E"));
+    p(s)
+}
+
+fn elevation(s: &str) -> ParseResult<'_, ParsedHeight> {
+    let p = satisfy(|c| c >= 'a' && c <= 'z');
+    let mut p = map(p, |c| ParsedHeight::Elevation((c as u8) - ASCII_OFFSET));
+    p(s)
+}
@@ -1,17 +1,19 @@
 use std::cmp::Ordering;
 use Ordering::{Equal, Greater, Less};
 
-pub fn part1(data: String) -> String {
-    parse(&data)
+use crate::days::{Answer, Day, Params, Part, Puzzle, Year};
+use crate::error::SolveError;
+
+fn solve_part1(packet_pairs: &[PacketPair]) -> Answer {
+    packet_pairs
         .iter()
         .enumerate()
         .filter_map(|(i, (p1, p2))| if p1 < p2 { Some(i + 1) } else { None })
         .sum::<usize>()
-        .to_string()
+        .into()
 }
 
-pub fn part2(data: String) -> String {
-    let packet_pairs = parse(&data);
+fn solve_part2(packet_pairs: &[PacketPair]) -> Answer {
     let mut packets: Vec<&Packet> = packet_pairs
         .iter()
         .flat_map(|(p1, p2)| vec![p1, p2])
@@ -34,7 +36,47 @@ pub fn part2(data: String) -> String {
             }
         })
         .product::<usize>()
-        .to_string()
+        .into()
+}
+
+const EXAMPLE: &str = include_str!("../../examples/2022/day13/example.txt");
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    type Input = PacketPairs;
+
+    fn year(&self) -> Year {
+        2022
+    }
+
+    fn day(&self) -> Day {
+        13
+    }
+
+    fn title(&self) -> &'static str {
+        "Distress Signal"
+    }
+
+    fn example(&self) -> &'static str {
+        EXAMPLE
+    }
+
+    fn parse(&self, data: &str) -> Result<Self::Input, SolveError> {
+        parse(data)
+    }
+
+    fn solve(
+        &self,
+        part: Part,
+        packet_pairs: &Self::Input,
+        _params: &Params,
+    ) -> Result<Answer, SolveError> {
+        match part {
+            1 => Ok(solve_part1(packet_pairs)),
+            _ => Ok(solve_part2(packet_pairs)),
+        }
+    }
 }
 
 fn divider(n: u32) -> Packet {
@@ -42,7 +84,7 @@ fn divider(n: u32) -> Packet {
 }
 
 #[derive(Debug, PartialEq, Eq)]
-enum Packet {
+pub enum Packet {
     Integer(u32),
     List(Vec<Packet>),
 }
@@ -99,22 +141,23 @@ type PacketPair = (Packet, Packet);
 
 type PacketPairs = Vec<PacketPair>;
 
-fn parse(s: &str) -> PacketPairs {
-    let (_rest, packet_pairs) = packet_pairs(s).unwrap();
-    packet_pairs
+fn parse(s: &str) -> Result<PacketPairs, SolveError> {
+    Ok(parse_all(s, packet_pairs)?)
 }
 
 use nom::{
     branch::alt,
     bytes::complete::tag,
     character::complete::u32,
-    combinator::map,
     combinator::value,
+    combinator::{cut, map},
+    error::context,
     multi::separated_list0,
     sequence::{delimited, separated_pair, tuple},
-    IResult,
 };
 
+use crate::parser::{parse_all, ParseResult};
+
 #[derive(Clone)]
 enum PacketToken {
     Start,
@@ -123,40 +166,40 @@ enum PacketToken {
     EOL,
 }
 
-fn packet_pairs(s: &str) -> IResult<&str, PacketPairs> {
-    separated_list0(tuple((eol, eol)), packet_pair)(s)
+fn packet_pairs(s: &str) -> ParseResult<'_, PacketPairs> {
+    separated_list0(tuple((eol, eol)), cut(packet_pair))(s)
 }
-fn packet_pair(s: &str) -> IResult<&str, PacketPair> {
-    separated_pair(packet, eol, packet)(s)
+fn packet_pair(s: &str) -> ParseResult<'_, PacketPair> {
+    context("packet pair", separated_pair(packet, eol, packet))(s)
 }
 
-fn packet(s: &str) -> IResult<&str, Packet> {
+fn packet(s: &str) -> ParseResult<'_, Packet> {
     alt((integer, list))(s)
 }
 
-fn integer(s: &str) -> IResult<&str, Packet> {
+fn integer(s: &str) -> ParseResult<'_, Packet> {
     map(u32, |n| Packet::Integer(n))(s)
 }
 
-fn list(s: &str) -> IResult<&str, Packet> {
+fn list(s: &str) -> ParseResult<'_, Packet> {
     let p = delimited(start, separated_list0(sep, packet), end);
     let mut p = map(p, |nodes| Packet::List(nodes));
     p(s)
 }
 
-fn start(s: &str) -> IResult<&str, PacketToken> {
+fn start(s: &str) -> ParseResult<'_, PacketToken> {
     value(PacketToken::Start, tag("["))(s)
 }
 
-fn end(s: &str) -> IResult<&str, PacketToken> {
+fn end(s: &str) -> ParseResult<'_, PacketToken> {
     value(PacketToken::End, tag("]"))(s)
 }
 
-fn sep(s: &str) -> IResult<&str, PacketToken> {
+fn sep(s: &str) -> ParseResult<'_, PacketToken> {
     value(PacketToken::Sep, tag(","))(s)
 }
 
-fn eol(s: &str) -> IResult<&str, PacketToken> {
+fn eol(s: &str) -> ParseResult<'_, PacketToken> {
     value(PacketToken::EOL, tag("\n"))(s)
 }
 
@@ -0,0 +1,386 @@
+use crate::check;
+use crate::coord::{Coord, ICoord, Orientation};
+use crate::days::{Answer, Day, Param, Params, Part, Puzzle, Year};
+use crate::error::{ParseError, SolveError};
+use std::collections::HashSet;
+
+const SAND_SOURCE_X: u32 = 500;
+const SAND_SOURCE_Y: u32 = 0;
+
+const PARAMS: &[Param] = &[
+    Param {
+        name: "source_x",
+        default: SAND_SOURCE_X as i64,
+        help: "x coordinate sand pours in from",
+    },
+    Param {
+        name: "source_y",
+        default: SAND_SOURCE_Y as i64,
+        help: "y coordinate sand pours in from",
+    },
+];
+
+fn solve_part1(scan: &Scan, source: Sandgrain) -> Result<Answer, SolveError> {
+    let mut filled = scan.filled.clone();
+    let deepest_y = scan.deepest_y;
+    check_source(&filled, &source)?;
+
+    let mut units = 0;
+    loop {
+        match flow_sand(source, &filled, Bottom::Abyss(deepest_y)) {
+            SandMove::LostToAbyss => break,
+            SandMove::OffEdge => return Err(off_edge()),
+            SandMove::AtRest(grain) => filled.insert(grain),
+        };
+        units += 1;
+    }
+
+    Ok(units.into())
+}
+
+fn solve_part2(scan: &Scan, source: Sandgrain) -> Result<Answer, SolveError> {
+    let mut filled = scan.filled.clone();
+    let deepest_y = scan.deepest_y;
+    check_source(&filled, &source)?;
+    let floor_y = deepest_y
+        .checked_add(2)
+        .ok_or_else(|| SolveError::unsolvable("the floor is too deep to reach"))?;
+    if source.y() >= floor_y {
+        return Err(SolveError::unsolvable("sand source is below the floor"));
+    }
+
+    let mut units = 1;
+    loop {
+        match flow_sand(source, &filled, Bottom::Floor(floor_y)) {
+            SandMove::LostToAbyss => return Err(SolveError::unsolvable("shouldn't lose sand")),
+            SandMove::OffEdge => return Err(off_edge()),
+            SandMove::AtRest(grain) => {
+                if grain == source {
+                    break;
+                } else {
+                    filled.insert(grain);
+                }
+            }
+        };
+        units += 1;
+    }
+
+    Ok(units.into())
+}
+
+// Sand can't pour in from inside rock; it would come to rest there forever.
+fn check_source(filled: &Fillmap, source: &Sandgrain) -> Result<(), SolveError> {
+    if filled.contains(source) {
+        return Err(SolveError::Unsolvable(format!(
+            "sand source {},{} is inside rock",
+            source.x(),
+            source.y()
+        )));
+    }
+    Ok(())
+}
+
+const EXAMPLE: &str = include_str!("../../examples/2022/day14/example.txt");
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    type Input = Scan;
+
+    fn year(&self) -> Year {
+        2022
+    }
+
+    fn day(&self) -> Day {
+        14
+    }
+
+    fn title(&self) -> &'static str {
+        "Regolith Reservoir"
+    }
+
+    fn example(&self) -> &'static str {
+        EXAMPLE
+    }
+
+    fn params(&self, _part: Part) -> &'static [Param] {
+        PARAMS
+    }
+
+    fn check(&self, data: &str) -> Vec<ParseError> {
+        let mut problems = check::common(data);
+        problems.extend(check_segments(data));
+        problems
+    }
+
+    fn parse(&self, data: &str) -> Result<Self::Input, SolveError> {
+        parse_scan(data)
+    }
+
+    fn solve(&self, part: Part, scan: &Self::Input, params: &Params) -> Result<Answer, SolveError> {
+        let source = Sandgrain::new(params.get("source_x")?, params.get("source_y")?);
+        match part {
+            1 => solve_part1(scan, source),
+            _ => solve_part2(scan, source),
+        }
+    }
+}
+
+pub struct Scan {
+    filled: Fillmap,
+    deepest_y: u32,
+}
+
+fn parse_scan(data: &str) -> Result<Scan, SolveError> {
+    let (filled, deepest_y) = build_scan(parse(data)?)?;
+    Ok(Scan { filled, deepest_y })
+}
+
+// Every segment of rock must be horizontal or vertical, which building the scan only reports the
+// first exception to, without saying where it is.
+fn check_segments(data: &str) -> Vec<ParseError> {
+    let mut problems = vec![];
+    for line in data.lines() {
+        let coords = line
+            .split(" -> ")
+            .map(|coord| {
+                let (x, y) = coord.trim_end().split_once(',')?;
+                Some((coord, x.parse::<u32>().ok()?, y.parse::<u32>().ok()?))
+            })
+            .collect::<Option<Vec<_>>>();
+        // malformed coords are left for the parser to report
+        let Some(coords) = coords else { continue };
+        for pair in coords.windows(2) {
+            let ((_, x1, y1), (at, x2, y2)) = (pair[0], pair[1]);
+            if (x1 == x2) == (y1 == y2) {
+                problems.push(ParseError::at(
+                    data,
+                    at,
+                    format!(
+                        "segment {},{} -> {},{} isn't horizontal or vertical",
+                        x1, y1, x2, y2
+                    ),
+                ));
+            }
+        }
+    }
+    problems
+}
+
+fn build_scan(paths: Vec<Path>) -> Result<(Fillmap, u32), SolveError> {
+    let mut deepest_y = 0;
+    let mut filled: Fillmap = Fillmap::new();
+    for path in paths.iter() {
+        for coord in path.coords()? {
+            if coord.y() > deepest_y {
+                deepest_y = coord.y()
+            }
+            filled.insert(coord);
+        }
+    }
+    Ok((filled, deepest_y))
+}
+
+type Sandgrain = Coord<u32>;
+type Fillmap = HashSet<Sandgrain>;
+
+enum SandMove {
+    LostToAbyss,
+    /// It would flow past the edge of the coordinates, e.g. left of x = 0.
+    OffEdge,
+    AtRest(Sandgrain),
+}
+
+fn off_edge() -> SolveError {
+    SolveError::unsolvable("sand flows off the edge of the scan, move its source further in")
+}
+
+enum Bottom {
+    Abyss(u32),
+    Floor(u32),
+}
+
+fn flow_sand(grain: Sandgrain, filled: &Fillmap, deepest: Bottom) -> SandMove {
+    let mut g = grain.clone();
+
+    loop {
+        let Some(next_y) = g.y().checked_add(1) else {
+            return SandMove::OffEdge;
+        };
+        let below = Coord::new(g.x(), next_y);
+        match deepest {
+            Bottom::Abyss(d) => {
+                if below.y() > d {
+                    return SandMove::LostToAbyss;
+                }
+            }
+            Bottom::Floor(d) => {
+                if below.y() == d {
+                    return SandMove::AtRest(g);
+                }
+            }
+        }
+        if !filled.contains(&below) {
+            g = below;
+            continue;
+        }
+
+        let Some(left_x) = g.x().checked_sub(1) else {
+            return SandMove::OffEdge;
+        };
+        let left = Coord::new(left_x, next_y);
+        if !filled.contains(&left) {
+            g = left;
+            continue;
+        }
+
+        let Some(right_x) = g.x().checked_add(1) else {
+            return SandMove::OffEdge;
+        };
+        let right = Coord::new(right_x, next_y);
+        if !filled.contains(&right) {
+            g = right;
+            continue;
+        }
+
+        return SandMove::AtRest(g);
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Path(Vec<Coord<u32>>);
+
+impl<'a> Path {
+    fn coords(&'a self) -> Result<Vec<Coord<u32>>, SolveError> {
+        let mut segments = vec![];
+        for pair in self.0.windows(2) {
+            let from = &pair[0];
+            let to = &pair[1];
+            segments.push(Segment { from, to });
+        }
+        let mut coords = vec![];
+        for segment in segments.iter() {
+            coords.extend(segment.coords()?);
+        }
+        Ok(coords)
+    }
+}
+
+struct Segment<'a> {
+    from: &'a Coord<u32>,
+    to: &'a Coord<u32>,
+}
+
+impl<'a> Segment<'a> {
+    fn coords(&self) -> Result<SegmentCoordsIterator, SolveError> {
+        let mut coords = vec![&self.from, &self.to];
+        coords.sort();
+        let start = coords[0];
+        let end = coords[1];
+        let current = (*start).clone();
+        let dir = if start.x() != end.x() && start.y() == end.y() {
+            Orientation::Horizontal
+        } else if start.x() == end.x() && start.y() != end.y() {
+            Orientation::Vertical
+        } else {
+            return Err(SolveError::parse(format!(
+                "coords not aligned horizontally or vertically: {:?} -> {:?}",
+                self.from, self.to
+            )));
+        };
+
+        Ok(SegmentCoordsIterator {
+            current,
+            end,
+            dir,
+            finished: false,
+        })
+    }
+}
+
+struct SegmentCoordsIterator<'a> {
+    current: Coord<u32>,
+    end: &'a Coord<u32>,
+    dir: Orientation,
+    finished: bool,
+}
+
+impl<'a> Iterator for SegmentCoordsIterator<'a> {
+    type Item = Coord<u32>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        let prev_current = self.current.clone();
+
+        if &self.current == self.end {
+            self.finished = true;
+        } else {
+            self.current = match self.dir {
+                Orientation::Horizontal => Coord::new(self.current.x() + 1, self.current.y()),
+                Orientation::Vertical => Coord::new(self.current.x(), self.current.y() + 1),
+            };
+        }
+
+        Some(prev_current)
+    }
+}
+
+fn parse(s: &str) -> Result<Vec<Path>, SolveError> {
+    Ok(parse_all(s, paths)?)
+}
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::u32,
+    combinator::{cut, map},
+    error::context,
+    multi::{separated_list0, separated_list1},
+    sequence::separated_pair,
+};
+
+use crate::parser::{parse_all, ParseResult};
+
+fn paths(s: &str) -> ParseResult<'_, Vec<Path>> {
+    separated_list0(tag("\n"), cut(path))(s)
+}
+
+fn path(s: &str) -> ParseResult<'_, Path> {
+    let p = separated_list1(tag(" -> "), cut(coord));
+    let mut p = map(p, |coords| Path(coords));
+    p(s)
+}
+
+fn coord(s: &str) -> ParseResult<'_, Coord<u32>> {
+    let p = separated_pair(u32, tag(","), u32);
+    let p = map(p, |(x, y)| Coord::new(x, y));
+    context("coord", p)(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn it_parses_paths() {
+        let data = "\
+498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9";
+        assert_eq!(
+            parse(data).unwrap(),
+            vec![
+                Path(vec![
+                    Coord::new(498, 4),
+                    Coord::new(498, 6),
+                    Coord::new(496, 6)
+                ]),
+                Path(vec![
+                    Coord::new(503, 4),
+                    Coord::new(502, 4),
+                    Coord::new(502, 9),
+                    Coord::new(494, 9)
+                ])
+            ]
+        );
+    }
+}
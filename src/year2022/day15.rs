@@ -0,0 +1,411 @@
+use std::collections::HashSet;
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::i64,
+    combinator::{cut, map},
+    error::context,
+    multi::separated_list0,
+    sequence::{pair, preceded, separated_pair, tuple},
+};
+
+use crate::coord::{Coord, ICoord};
+use crate::days::{Answer, Day, Param, Params, Part, Puzzle, Variant, Year};
+use crate::error::SolveError;
+use crate::parser::{parse_all, ParseResult};
+
+const ROW: i64 = 2_000_000;
+const COORD_LIMITS: i64 = 4_000_000;
+// Coordinates are kept this close to 0 so sensors' ranges, and the edges just beyond them, can be
+// worked out without overflowing.
+const MAX_COORD: i64 = i64::MAX / 16;
+
+const PART1_PARAMS: &[Param] = &[Param {
+    name: "row",
+    default: ROW,
+    help: "row to count beaconless positions in",
+}];
+const PART2_PARAMS: &[Param] = &[Param {
+    name: "coord_limits",
+    default: COORD_LIMITS,
+    help: "largest x and y coordinate the distress beacon can be at",
+}];
+const PART2_VARIANTS: &[Variant] = &[Variant {
+    name: "edges",
+    help: "look where the edges of sensors' ranges cross, rather than scanning every row",
+}];
+
+fn solve_part1(sensors: &[Sensor], row_num: i64) -> Result<Answer, SolveError> {
+    let row_coverage = RowCoverage::build_for_row(sensors, row_num);
+
+    Ok(row_coverage.len().into())
+}
+
+fn solve_part2(sensors: &[Sensor], limits: i64) -> Result<Answer, SolveError> {
+    for row_num in 0..=limits {
+        let row_coverage = RowCoverage::build_for_row(sensors, row_num);
+
+        match row_coverage.not_covered_between(0, limits) {
+            Some(xs) => {
+                if xs.len() != 1 || xs[0].start != xs[0].end {
+                    return Err(SolveError::Unsolvable(format!(
+                        "expected a single uncovered position in row {}, found {:?}",
+                        row_num, xs
+                    )));
+                }
+                return tuning_frequency(xs[0].start, row_num);
+            }
+            None => (),
+        }
+    }
+    Err(SolveError::unsolvable("didn't find coord"))
+}
+
+// The distress beacon is the only position no sensor covers, so it's just beyond the edge of a
+// sensor's range, hemmed in by other edges or the edge of the search area. Those edges are the
+// diagonals x + y = sum and x - y = diff, so it's where two of them cross, or where one meets the
+// edge of the search area, or in a corner.
+fn solve_part2_edges(sensors: &[Sensor], limits: i64) -> Result<Answer, SolveError> {
+    let mut sums = vec![];
+    let mut diffs = vec![];
+    for sensor in sensors {
+        let (x, y, beyond) = (sensor.coord.x(), sensor.coord.y(), sensor.range() + 1);
+        sums.extend([x + y - beyond, x + y + beyond]);
+        diffs.extend([x - y - beyond, x - y + beyond]);
+    }
+
+    let mut candidates = vec![(0, 0), (0, limits), (limits, 0), (limits, limits)];
+    for &sum in &sums {
+        candidates.extend([
+            (0, sum),
+            (limits, sum - limits),
+            (sum, 0),
+            (sum - limits, limits),
+        ]);
+        for &diff in &diffs {
+            if (sum + diff).rem_euclid(2) == 0 {
+                candidates.push(((sum + diff) / 2, (sum - diff) / 2));
+            }
+        }
+    }
+    for &diff in &diffs {
+        candidates.extend([
+            (0, -diff),
+            (limits, limits - diff),
+            (diff, 0),
+            (diff + limits, limits),
+        ]);
+    }
+
+    let mut uncovered = candidates
+        .into_iter()
+        .filter(|&(x, y)| (0..=limits).contains(&x) && (0..=limits).contains(&y))
+        .filter(|&(x, y)| !sensors.iter().any(|s| s.covers(&Coord::new(x, y))))
+        .collect::<Vec<_>>();
+    uncovered.sort();
+    uncovered.dedup();
+    match uncovered[..] {
+        [(x, y)] => tuning_frequency(x, y),
+        [] => Err(SolveError::unsolvable("didn't find coord")),
+        _ => Err(SolveError::Unsolvable(format!(
+            "expected a single uncovered position, found {:?}",
+            uncovered
+        ))),
+    }
+}
+
+fn tuning_frequency(x: i64, y: i64) -> Result<Answer, SolveError> {
+    x.checked_mul(COORD_LIMITS)
+        .and_then(|frequency| frequency.checked_add(y))
+        .map(Answer::from)
+        .ok_or_else(|| SolveError::unsolvable("tuning frequency overflowed"))
+}
+
+// Sensors and beacons must be within `MAX_COORD` of 0, as must the row or limits searched.
+fn check_coords(sensors: &[Sensor], param: &str, value: i64) -> Result<(), SolveError> {
+    let in_range = |n: i64| (-MAX_COORD..=MAX_COORD).contains(&n);
+    if !in_range(value) {
+        return Err(SolveError::InvalidParam(format!(
+            "{} is out of range for {:?}, it must be within {} of 0",
+            value, param, MAX_COORD
+        )));
+    }
+    let far_out = sensors
+        .iter()
+        .flat_map(|s| [s.coord, s.closest_beacon])
+        .find(|c| !in_range(c.x()) || !in_range(c.y()));
+    match far_out {
+        Some(c) => Err(SolveError::Unsolvable(format!(
+            "{},{} is too far out to search, coordinates must be within {} of 0",
+            c.x(),
+            c.y(),
+            MAX_COORD
+        ))),
+        None => Ok(()),
+    }
+}
+
+const EXAMPLE: &str = include_str!("../../examples/2022/day15/example.txt");
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    type Input = Vec<Sensor>;
+
+    fn year(&self) -> Year {
+        2022
+    }
+
+    fn day(&self) -> Day {
+        15
+    }
+
+    fn title(&self) -> &'static str {
+        "Beacon Exclusion Zone"
+    }
+
+    fn example(&self) -> &'static str {
+        EXAMPLE
+    }
+
+    fn params(&self, part: Part) -> &'static [Param] {
+        match part {
+            1 => PART1_PARAMS,
+            _ => PART2_PARAMS,
+        }
+    }
+
+    fn variants(&self, part: Part) -> &'static [Variant] {
+        match part {
+            1 => &[],
+            _ => PART2_VARIANTS,
+        }
+    }
+
+    fn parse(&self, data: &str) -> Result<Self::Input, SolveError> {
+        parse(data)
+    }
+
+    fn solve(
+        &self,
+        part: Part,
+        sensors: &Self::Input,
+        params: &Params,
+    ) -> Result<Answer, SolveError> {
+        let param = match part {
+            1 => "row",
+            _ => "coord_limits",
+        };
+        let value = params.get(param)?;
+        check_coords(sensors, param, value)?;
+        match part {
+            1 => solve_part1(sensors, value),
+            _ => match params.variant() {
+                Some("edges") => solve_part2_edges(sensors, value),
+                _ => solve_part2(sensors, value),
+            },
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Sensor {
+    coord: Coord<i64>,
+    closest_beacon: Coord<i64>,
+}
+
+impl Sensor {
+    fn range(&self) -> i64 {
+        self.coord.manhattan_distance(&self.closest_beacon)
+    }
+
+    fn covers(&self, coord: &Coord<i64>) -> bool {
+        self.coord.manhattan_distance(coord) <= self.range()
+    }
+
+    fn range_at_row(&self, row: i64) -> Option<MergableRangeInclusive> {
+        let md = self.coord.manhattan_distance(&self.closest_beacon);
+
+        let md_x = md - (row - self.coord.y()).abs();
+
+        if md_x >= 0 {
+            let start = self.coord.x() - md_x;
+            let end = self.coord.x() + md_x;
+            Some(MergableRangeInclusive::new(start, end))
+        } else {
+            None
+        }
+    }
+}
+
+struct RowCoverage {
+    row_num: i64,
+    sensor_ranges: Vec<MergableRangeInclusive>,
+    beacons_in_row: HashSet<i64>,
+}
+
+impl RowCoverage {
+    fn new(row_num: i64) -> Self {
+        Self {
+            row_num,
+            sensor_ranges: vec![],
+            beacons_in_row: HashSet::new(),
+        }
+    }
+
+    fn build_for_row(sensors: &[Sensor], row_num: i64) -> Self {
+        let mut row_coverage = Self::new(row_num);
+        for sensor in sensors.iter() {
+            row_coverage.add_sensor(sensor);
+        }
+
+        row_coverage
+    }
+
+    fn add_sensor(&mut self, sensor: &Sensor) -> () {
+        if let Some(range) = sensor.range_at_row(self.row_num) {
+            self.add_range(range);
+        }
+        self.add_beacon(&sensor.closest_beacon);
+    }
+
+    fn add_beacon(&mut self, beacon: &Coord<i64>) -> () {
+        if beacon.y() == self.row_num {
+            self.beacons_in_row.insert(beacon.x());
+        }
+    }
+
+    fn add_range(&mut self, new_range: MergableRangeInclusive) -> () {
+        self.sensor_ranges = new_range.merge_others(&self.sensor_ranges);
+        self.sensor_ranges.sort();
+    }
+
+    fn len(&self) -> i64 {
+        self.sensor_ranges
+            .iter()
+            .map(|range| {
+                let overlapping_beacons = self
+                    .beacons_in_row
+                    .iter()
+                    .filter(|beacon_x| range.contains(**beacon_x));
+                range.len() - overlapping_beacons.count() as i64
+            })
+            .sum::<i64>()
+    }
+
+    fn not_covered_between(&self, start: i64, end: i64) -> Option<Vec<MergableRangeInclusive>> {
+        let mut not_covered = vec![];
+        let mut current_x = start;
+
+        for range in self.sensor_ranges.iter() {
+            if current_x < range.start {
+                not_covered.push(MergableRangeInclusive::new(current_x, range.start - 1));
+            }
+            current_x = (range.end + 1).clamp(0, end);
+        }
+
+        if current_x < end {
+            not_covered.push(MergableRangeInclusive::new(current_x, end));
+        }
+
+        if not_covered.is_empty() {
+            None
+        } else {
+            Some(not_covered)
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct MergableRangeInclusive {
+    start: i64,
+    end: i64,
+}
+
+impl MergableRangeInclusive {
+    fn new(start: i64, end: i64) -> Self {
+        Self { start, end }
+    }
+
+    fn merge_others(&self, others: &Vec<Self>) -> Vec<Self> {
+        let mut merged_ranges = vec![];
+        let mut merged = self.clone();
+        for r in others {
+            match merged.merge(&r) {
+                Ok(new_merged) => merged = new_merged,
+                Err(NotOverlapping {}) => merged_ranges.push(r.clone()),
+            }
+        }
+        merged_ranges.push(merged);
+        merged_ranges
+    }
+
+    fn merge(&self, other: &Self) -> Result<Self, NotOverlapping> {
+        if self.is_overlap(other) {
+            let start = self.start.min(other.start);
+            let end = self.end.max(other.end);
+            Ok(Self { start, end })
+        } else {
+            Err(NotOverlapping {})
+        }
+    }
+
+    fn is_overlap(&self, other: &Self) -> bool {
+        self.contains(other.start)
+            || self.contains(other.end)
+            || other.contains(self.start)
+            || other.contains(self.end)
+    }
+
+    fn contains(&self, v: i64) -> bool {
+        self.start <= v && self.end >= v
+    }
+
+    fn len(&self) -> i64 {
+        self.end - self.start + 1
+    }
+}
+
+struct NotOverlapping {}
+
+fn parse(s: &str) -> Result<Vec<Sensor>, SolveError> {
+    Ok(parse_all(s, readings)?)
+}
+
+fn readings(s: &str) -> ParseResult<'_, Vec<Sensor>> {
+    separated_list0(tag("\n"), cut(sensor))(s)
+}
+// Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+fn sensor(s: &str) -> ParseResult<'_, Sensor> {
+    let p = pair(sensor_coord, beacon_coord);
+    let p = map(p, |(sensor, beacon)| Sensor {
+        coord: sensor,
+        closest_beacon: beacon,
+    });
+    context("sensor reading", p)(s)
+}
+
+//Sensor at x=2, y=18
+fn sensor_coord(s: &str) -> ParseResult<'_, Coord<i64>> {
+    preceded(tag("Sensor at "), coord)(s)
+}
+
+//: closest beacon is at x=2, y=15
+fn beacon_coord(s: &str) -> ParseResult<'_, Coord<i64>> {
+    preceded(tag(": closest beacon is at "), coord)(s)
+}
+
+//x=2, y=18
+fn coord(s: &str) -> ParseResult<'_, Coord<i64>> {
+    let p = separated_pair(coord_part("x"), tag(", "), coord_part("y"));
+    let mut p = map(p, |(x, y)| Coord::new(x, y));
+    p(s)
+}
+
+//x=2
+fn coord_part<'a>(axis: &'a str) -> impl Fn(&'a str) -> ParseResult<'a, i64> {
+    move |s: &'a str| {
+        let mut p = preceded(tuple((tag(axis), tag("="))), i64);
+        p(s)
+    }
+}
@@ -8,36 +8,164 @@ use nom::{
     bytes::complete::tag,
     character::complete::anychar,
     character::complete::u32,
-    combinator::map,
+    combinator::{cut, map},
+    error::context,
     multi::separated_list0,
     sequence::{preceded, tuple},
-    IResult,
 };
 
+use crate::check;
+use crate::days::{Answer, Day, Param, Params, Part, Puzzle, Variant, Year};
+use crate::error::{ParseError, SolveError};
 use crate::graph::Graph;
+use crate::parser::{parse_all, ParseResult};
 
 const TOTAL_MINUTES: Minute = 30;
 const OPEN_TIME: Minute = 1;
 const TRAVEL_TIME: Minute = 1;
 const NO_FLOW: FlowRate = 0;
 
-pub fn part1(data: String) -> String {
+const PART1_PARAMS: &[Param] = &[Param {
+    name: "minutes",
+    default: TOTAL_MINUTES as i64,
+    help: "minutes before the volcano erupts",
+}];
+const PART1_VARIANTS: &[Variant] = &[Variant {
+    name: "exhaustive",
+    help: "try every order of opening the valves, rather than pruning with branch and bound",
+}];
+
+fn solve_part1(rooms: &Rooms, total_minutes: Minute) -> Result<Answer, SolveError> {
     let current_valve = hash_valve_label(('A', 'A'));
-    let rooms = parse(&data);
+    if !rooms.contains_key(&current_valve) {
+        return Err(SolveError::unsolvable("no starting valve AA"));
+    }
 
     let root = SearchState {
         score: NO_FLOW,
         current_valve,
-        flows: build_flows(&rooms),
-        remaining_minutes: TOTAL_MINUTES,
+        flows: build_flows(rooms),
+        remaining_minutes: total_minutes,
     };
 
-    root.search(NO_FLOW, &build_shortest_from_to(current_valve, &rooms))
-        .to_string()
+    Ok(root
+        .search(NO_FLOW, &build_shortest_from_to(current_valve, rooms))
+        .into())
+}
+
+fn solve_part1_exhaustive(rooms: &Rooms, total_minutes: Minute) -> Result<Answer, SolveError> {
+    let start = hash_valve_label(('A', 'A'));
+    if !rooms.contains_key(&start) {
+        return Err(SolveError::unsolvable("no starting valve AA"));
+    }
+    let valves = rooms
+        .values()
+        .filter(|room| room.flow_rate > NO_FLOW)
+        .map(|room| (room.valve_label, room.flow_rate))
+        .collect::<Vec<_>>();
+    if valves.len() > OpenValves::BITS as usize {
+        return Err(SolveError::Unsolvable(format!(
+            "{} valves with flow is too many to try every order of",
+            valves.len()
+        )));
+    }
+
+    let shortest_dists_from_to = build_shortest_from_to(start, rooms);
+    Ok(most_pressure(&valves, &shortest_dists_from_to, start, total_minutes, 0).into())
 }
 
-pub fn part2(_data: String) -> String {
-    panic!("not implemented");
+// Bit i is set once the ith valve with flow is open.
+type OpenValves = u64;
+
+// The most pressure that can be released from `valve` in the minutes left, opening the `valves`
+// that aren't yet.
+fn most_pressure(
+    valves: &[(ValveLabel, FlowRate)],
+    shortest_dists_from_to: &ShortestDistFromTo,
+    valve: ValveLabel,
+    remaining_minutes: Minute,
+    open: OpenValves,
+) -> FlowRate {
+    valves
+        .iter()
+        .enumerate()
+        .filter(|&(i, _)| open & (1 << i) == 0)
+        .filter_map(|(i, &(next, flow))| {
+            let travel_time = *shortest_dists_from_to.get(&(valve, next))?;
+            let remaining = remaining_minutes.checked_sub(travel_time + OPEN_TIME)?;
+            Some(
+                flow * remaining
+                    + most_pressure(
+                        valves,
+                        shortest_dists_from_to,
+                        next,
+                        remaining,
+                        open | 1 << i,
+                    ),
+            )
+        })
+        .max()
+        .unwrap_or(NO_FLOW)
+}
+
+const EXAMPLE: &str = include_str!("../../examples/2022/day16/example.txt");
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    type Input = Rooms;
+
+    fn year(&self) -> Year {
+        2022
+    }
+
+    fn day(&self) -> Day {
+        16
+    }
+
+    fn title(&self) -> &'static str {
+        "Proboscidea Volcanium"
+    }
+
+    fn example(&self) -> &'static str {
+        EXAMPLE
+    }
+
+    fn parts(&self) -> &'static [Part] {
+        &[1]
+    }
+
+    fn params(&self, _part: Part) -> &'static [Param] {
+        PART1_PARAMS
+    }
+
+    fn variants(&self, _part: Part) -> &'static [Variant] {
+        PART1_VARIANTS
+    }
+
+    fn check(&self, data: &str) -> Vec<ParseError> {
+        let mut problems = check::common(data);
+        problems.extend(check_tunnels(data));
+        problems
+    }
+
+    fn parse(&self, data: &str) -> Result<Self::Input, SolveError> {
+        parse(data)
+    }
+
+    fn solve(
+        &self,
+        _part: Part,
+        rooms: &Self::Input,
+        params: &Params,
+    ) -> Result<Answer, SolveError> {
+        let total_minutes = params.get("minutes")?;
+        check_pressure(rooms, total_minutes)?;
+        match params.variant() {
+            Some("exhaustive") => solve_part1_exhaustive(rooms, total_minutes),
+            _ => solve_part1(rooms, total_minutes),
+        }
+    }
 }
 
 type ValveLabel = u64;
@@ -50,6 +178,17 @@ type ShortestDistFromTo = HashMap<(ValveLabel, ValveLabel), Minute>;
 
 type Flows = HashTrieMap<ValveLabel, FlowRate>;
 
+// No way of opening the valves releases more pressure than all of them open from the start, so if
+// that can be counted, every score on the way to the most pressure can be.
+fn check_pressure(rooms: &Rooms, total_minutes: Minute) -> Result<(), SolveError> {
+    rooms
+        .values()
+        .try_fold(NO_FLOW, |flow, room| flow.checked_add(room.flow_rate))
+        .and_then(|flow| flow.checked_mul(total_minutes))
+        .map(|_| ())
+        .ok_or_else(|| SolveError::unsolvable("too much pressure to count"))
+}
+
 fn build_flows(rooms: &Rooms) -> Flows {
     rooms.values().fold(Flows::new(), |acc, room| {
         acc.insert(room.valve_label, room.flow_rate)
@@ -110,6 +249,13 @@ impl SearchState {
         best_found: FlowRate,
         shortest_dists_from_to: &ShortestDistFromTo,
     ) -> FlowRate {
+        tracing::trace!(
+            valve = ?self.current_valve,
+            score = self.score,
+            remaining_minutes = self.remaining_minutes,
+            best_found,
+            "visiting state"
+        );
         if self.reject(best_found) {
             self.score.max(best_found)
         } else if self.accept() {
@@ -155,7 +301,9 @@ impl SearchState {
         self.unopened_valves()
             .into_iter()
             .filter_map(|unopened_valve| {
-                let travel_time = shortest_dists_from_to[&(self.current_valve, unopened_valve)];
+                // valves that can't be reached are left out
+                let travel_time =
+                    *shortest_dists_from_to.get(&(self.current_valve, unopened_valve))?;
 
                 if unopened_valve != self.current_valve
                     && travel_time + OPEN_TIME < self.remaining_minutes
@@ -210,27 +358,78 @@ fn hash_valve_label(label: (char, char)) -> ValveLabel {
 }
 
 #[derive(Debug, PartialEq)]
-struct Room {
+pub struct Room {
     valve_label: ValveLabel,
     flow_rate: FlowRate,
     paths: Vec<ValveLabel>,
 }
 
-fn parse(s: &str) -> Rooms {
-    let (_rest, rooms) = rooms(s).unwrap();
+fn parse(s: &str) -> Result<Rooms, SolveError> {
+    let rooms = parse_all(s, rooms)?;
 
-    rooms
+    let rooms: Rooms = rooms
         .into_iter()
         .map(|room| (room.valve_label, room))
-        .collect()
+        .collect();
+
+    for room in rooms.values() {
+        if room.paths.iter().any(|path| !rooms.contains_key(path)) {
+            return Err(SolveError::parse("tunnel leads to an undefined valve"));
+        }
+    }
+
+    Ok(rooms)
 }
 
-fn rooms(s: &str) -> IResult<&str, Vec<Room>> {
-    separated_list0(tag("\n"), room)(s)
+// Each valve must be defined once, and every tunnel must lead to one that's defined. The parser
+// reports the first undefined valve without saying where it is, and lets later definitions of a
+// valve replace earlier ones.
+fn check_tunnels(data: &str) -> Vec<ParseError> {
+    let mut problems = vec![];
+    let mut defined: HashMap<&str, usize> = HashMap::new();
+    for (i, line) in data.lines().enumerate() {
+        let Some(label) = line.strip_prefix("Valve ").and_then(|rest| rest.get(..2)) else {
+            continue;
+        };
+        if let Some(first) = defined.insert(label, i + 1) {
+            defined.insert(label, first);
+            problems.push(ParseError::at(
+                data,
+                label,
+                format!("valve {} is already defined on line {}", label, first),
+            ));
+        }
+    }
+
+    for line in data.lines() {
+        let Some((_, tunnels)) = line
+            .split_once("; tunnels lead to valves ")
+            .or_else(|| line.split_once("; tunnel leads to valve "))
+        else {
+            continue;
+        };
+        for to in tunnels.trim_end().split(", ") {
+            if !defined.contains_key(to) {
+                problems.push(ParseError::at(
+                    data,
+                    to,
+                    format!("tunnel leads to valve {}, which isn't defined", to),
+                ));
+            }
+        }
+    }
+    if !defined.is_empty() && !defined.contains_key("AA") {
+        problems.push(ParseError::new("there's no starting valve AA"));
+    }
+    problems
+}
+
+fn rooms(s: &str) -> ParseResult<'_, Vec<Room>> {
+    separated_list0(tag("\n"), cut(room))(s)
 }
 
 //Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
-fn room(s: &str) -> IResult<&str, Room> {
+fn room(s: &str) -> ParseResult<'_, Room> {
     let tunnels_sep = alt((
         tag("; tunnels lead to valves "),
         tag("; tunnel leads to valve "),
@@ -240,28 +439,28 @@ fn room(s: &str) -> IResult<&str, Room> {
         preceded(tag(" has flow rate="), flow_rate),
         preceded(tunnels_sep, valve_label_list),
     ));
-    let mut p = map(p, |(valve_label, flow_rate, paths)| Room {
+    let p = map(p, |(valve_label, flow_rate, paths)| Room {
         valve_label,
         flow_rate,
         paths,
     });
-    p(s)
+    context("valve", p)(s)
 }
 
 //AA
-fn valve_label(s: &str) -> IResult<&str, ValveLabel> {
+fn valve_label(s: &str) -> ParseResult<'_, ValveLabel> {
     let p = tuple((anychar, anychar));
     let mut p = map(p, hash_valve_label);
     p(s)
 }
 
 //0
-fn flow_rate(s: &str) -> IResult<&str, FlowRate> {
+fn flow_rate(s: &str) -> ParseResult<'_, FlowRate> {
     u32(s)
 }
 
 //DD, II, BB
-fn valve_label_list(s: &str) -> IResult<&str, Vec<ValveLabel>> {
+fn valve_label_list(s: &str) -> ParseResult<'_, Vec<ValveLabel>> {
     separated_list0(tag(", "), valve_label)(s)
 }
 
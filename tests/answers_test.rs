@@ -0,0 +1,79 @@
+use std::path::Path;
+use std::time::Duration;
+
+use aoc2022::answers::{check, Answers, Check};
+use aoc2022::runner::{Outcome, Status};
+
+const ANSWERS: &str = r#"
+[2022.day01]
+part1 = "24000"
+part2 = "45000"
+
+[2022.day10]
+part2 = """
+##..
+.##."""
+"#;
+
+fn solved(day: u8, part: u8, answer: &str) -> Outcome {
+    Outcome {
+        year: 2022,
+        day,
+        part,
+        status: Status::Solved(answer.into()),
+        elapsed: Duration::ZERO,
+        input: None,
+        allocs: None,
+        cached: false,
+    }
+}
+
+#[test]
+fn it_reads_answers_by_year_day_and_part() {
+    let answers = Answers::from_toml(ANSWERS).unwrap();
+    assert_eq!(answers.get(2022, 1, 1).unwrap(), "24000");
+    assert_eq!(answers.get(2022, 1, 2).unwrap(), "45000");
+    assert_eq!(answers.get(2022, 10, 2).unwrap(), "##..\n.##.");
+    assert_eq!(answers.get(2022, 10, 1), None);
+    assert_eq!(answers.get(2021, 1, 1), None);
+}
+
+#[test]
+fn it_round_trips_answers() {
+    let answers = Answers::from_toml(ANSWERS).unwrap();
+    let written = answers.to_toml().unwrap();
+    assert_eq!(Answers::from_toml(&written).unwrap(), answers);
+}
+
+#[test]
+fn it_rejects_badly_keyed_answers() {
+    assert!(Answers::from_toml("[2022.day01]\npart_one = \"1\"").is_err());
+    assert!(Answers::from_toml("[2022.first]\npart1 = \"1\"").is_err());
+    assert!(Answers::from_toml("[day01.day01]\npart1 = \"1\"").is_err());
+}
+
+#[test]
+fn it_treats_a_missing_file_as_empty() {
+    let answers = Answers::load(Path::new("./does_not_exist.toml")).unwrap();
+    assert_eq!(answers, Answers::default());
+}
+
+#[test]
+fn it_checks_outcomes_against_answers() {
+    let answers = Answers::from_toml(ANSWERS).unwrap();
+    assert_eq!(check(&answers, &solved(1, 1, "24000")), Check::Correct);
+    assert_eq!(
+        check(&answers, &solved(1, 2, "41000")),
+        Check::Incorrect {
+            expected: "45000".to_string(),
+            actual: "41000".to_string()
+        }
+    );
+    assert_eq!(check(&answers, &solved(2, 1, "15")), Check::Unrecorded);
+
+    let skipped = Outcome {
+        status: Status::MissingInput("./data/2022/day01.txt".into()),
+        ..solved(1, 1, "")
+    };
+    assert_eq!(check(&answers, &skipped), Check::NotSolved);
+}
@@ -0,0 +1,78 @@
+use aoc2022::attempts::{Attempts, Verdict, Warning};
+use aoc2022::days::Answer;
+
+const ATTEMPTS: &str = r#"
+[[2022.day01.part1]]
+answer = "71000"
+verdict = "too high"
+
+[[2022.day01.part1]]
+answer = "65000"
+verdict = "too low"
+
+[[2022.day01.part1]]
+answer = "68000"
+verdict = "wrong"
+
+[[2022.day05.part1]]
+answer = "CMZ"
+verdict = "correct"
+"#;
+
+fn warning(attempts: &Attempts, day: u8, answer: impl Into<Answer>) -> Option<Warning> {
+    attempts.warning(2022, day, 1, &answer.into())
+}
+
+#[test]
+fn it_reads_attempts_in_the_order_they_were_tried() {
+    let attempts = Attempts::from_toml(ATTEMPTS).unwrap();
+    let tried = attempts.get(2022, 1, 1);
+    assert_eq!(
+        tried
+            .iter()
+            .map(|a| (a.answer.as_str(), a.verdict))
+            .collect::<Vec<_>>(),
+        vec![
+            ("71000", Verdict::TooHigh),
+            ("65000", Verdict::TooLow),
+            ("68000", Verdict::Wrong)
+        ]
+    );
+    assert!(attempts.get(2022, 1, 2).is_empty());
+    assert_eq!(
+        Attempts::from_toml(&attempts.to_toml().unwrap()).unwrap(),
+        attempts
+    );
+}
+
+#[test]
+fn it_warns_about_answers_already_ruled_out() {
+    let attempts = Attempts::from_toml(ATTEMPTS).unwrap();
+    assert_eq!(
+        warning(&attempts, 1, 68000),
+        Some(Warning::Rejected(Verdict::Wrong))
+    );
+    assert_eq!(warning(&attempts, 1, 75000), Some(Warning::TooHigh(71000)));
+    assert_eq!(
+        warning(&attempts, 1, 65000),
+        Some(Warning::Rejected(Verdict::TooLow))
+    );
+    assert_eq!(warning(&attempts, 1, 1000), Some(Warning::TooLow(65000)));
+    assert_eq!(warning(&attempts, 1, 69000), None);
+    assert_eq!(warning(&attempts, 2, 69000), None);
+
+    assert_eq!(warning(&attempts, 5, "CMZ"), None);
+    assert_eq!(
+        warning(&attempts, 5, "MCD"),
+        Some(Warning::NotCorrect("CMZ".to_string()))
+    );
+}
+
+#[test]
+fn a_new_verdict_replaces_the_old() {
+    let mut attempts = Attempts::default();
+    attempts.insert(2022, 1, 1, "100".to_string(), Verdict::Wrong);
+    attempts.insert(2022, 1, 1, "100".to_string(), Verdict::TooHigh);
+    assert_eq!(attempts.get(2022, 1, 1).len(), 1);
+    assert_eq!(warning(&attempts, 1, 150), Some(Warning::TooHigh(100)));
+}
@@ -0,0 +1,47 @@
+use std::fs;
+use std::time::Duration;
+
+use aoc2022::batch::{input_files, run_batch, Flag};
+use aoc2022::days::{solver, Answer, Params};
+use aoc2022::runner::Status;
+
+#[test]
+fn every_input_is_run_and_problems_flagged() {
+    let dir = std::env::temp_dir().join("aoc2022_batch_test");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("old")).unwrap();
+    let day1 = solver(2022, 1).unwrap();
+    fs::write(dir.join("alice.txt"), day1.example()).unwrap();
+    fs::write(dir.join("bob.txt"), "1000\n2000x\n").unwrap();
+    fs::write(dir.join(".notes"), "ignored\n").unwrap();
+    fs::write(dir.join("old/carol.txt"), day1.example()).unwrap();
+
+    assert_eq!(
+        input_files(&dir).unwrap(),
+        vec![dir.join("alice.txt"), dir.join("bob.txt")]
+    );
+
+    let runs = run_batch(day1, &dir, &Params::new()).unwrap();
+    assert_eq!(runs.len(), 2);
+    let answers = runs[0]
+        .outcomes
+        .iter()
+        .map(|o| match &o.status {
+            Status::Solved(answer) => answer.clone(),
+            status => panic!("unexpected {:?}", status),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(answers, vec![Answer::Int(24000), Answer::Int(45000)]);
+    assert_eq!(runs[0].flags(None), vec![]);
+    assert_eq!(
+        runs[1].flags(None),
+        vec![Flag::ParseFailed(1), Flag::ParseFailed(2)]
+    );
+
+    let flags = runs[0].flags(Some(Duration::ZERO));
+    assert!(matches!(
+        flags[..],
+        [Flag::OverBudget(1, ..), Flag::OverBudget(2, ..)]
+    ));
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,33 @@
+use aoc2022::bench::bench;
+use aoc2022::days::{solver, Params};
+use aoc2022::error::SolveError;
+
+#[test]
+fn it_benchmarks_parse_and_solve() {
+    let day01 = solver(2022, 1).unwrap();
+    let report = bench(day01, 2, day01.example(), &Params::new(), 1, 5).unwrap();
+
+    assert_eq!(
+        (report.year, report.day, report.part, report.iterations),
+        (2022, 1, 2, 5)
+    );
+    assert!(report.parse.min <= report.parse.p95);
+    assert!(report.solve.min <= report.solve.p95);
+
+    let json = serde_json::to_value(&report).unwrap();
+    assert_eq!(json["day"], 1);
+    assert!(json["solve"]["median_ns"].is_u64());
+}
+
+#[test]
+fn it_fails_on_unimplemented_parts() {
+    let day16 = solver(2022, 16).unwrap();
+    assert!(matches!(
+        bench(day16, 2, day16.example(), &Params::new(), 0, 1),
+        Err(SolveError::NotImplemented {
+            year: 2022,
+            day: 16,
+            part: 2
+        })
+    ));
+}
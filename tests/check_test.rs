@@ -0,0 +1,116 @@
+use aoc2022::check::check_input;
+use aoc2022::days::{solver, solvers};
+
+// The line and message of each problem found with `data` as `day`'s input.
+fn problems(day: u8, data: &str) -> Vec<(usize, String)> {
+    check_input(solver(2022, day).unwrap(), data)
+        .into_iter()
+        .map(|p| (p.location.map_or(0, |l| l.line), p.message))
+        .collect()
+}
+
+#[test]
+fn examples_pass_their_checks() {
+    // days just scaffolded by `aoc new` have no example yet
+    for solver in solvers().filter(|solver| !solver.example().is_empty()) {
+        // the bundled examples are kept as they were added, e.g. day 2's lines end with a space
+        let problems = check_input(solver, solver.example())
+            .into_iter()
+            .filter(|p| p.message != "trailing whitespace")
+            .collect::<Vec<_>>();
+        assert_eq!(problems, vec![], "{} day {}", solver.year(), solver.day());
+    }
+}
+
+#[test]
+fn mangled_input_is_reported_before_parse_errors() {
+    let crlf = solver(2022, 1).unwrap().example().replace('\n', "\r\n");
+    assert_eq!(
+        problems(1, &crlf),
+        vec![(1, "14 of 14 lines end with CRLF".to_string())]
+    );
+    assert_eq!(
+        problems(4, "2-4,6-8\n2-3,4-5\n4-"),
+        vec![(
+            3,
+            "no newline at the end of the input, it may have been cut short".to_string()
+        )]
+    );
+    assert_eq!(problems(4, "2-4,6-8\n2-3,4-5x\n")[0].0, 2);
+}
+
+#[test]
+fn every_ragged_row_is_reported() {
+    assert_eq!(
+        problems(8, "30373\n2551\n65332\n337\n"),
+        vec![
+            (2, "row is 4 wide, but the first row is 5".to_string()),
+            (4, "row is 3 wide, but the first row is 5".to_string())
+        ]
+    );
+}
+
+#[test]
+fn heightmaps_need_one_start_and_end() {
+    assert_eq!(
+        problems(12, "SabE\nabcS\n"),
+        vec![(2, "another start 'S', the first is on line 1".to_string())]
+    );
+    assert_eq!(
+        problems(12, "Sabc\nabcd\n"),
+        vec![(0, "no end 'E' found".to_string())]
+    );
+}
+
+#[test]
+fn monkeys_are_numbered_in_order_and_throw_to_each_other() {
+    let example = solver(2022, 11).unwrap().example();
+    let mangled = example
+        .replace("Monkey 2:", "Monkey 5:")
+        .replace("If true: throw to monkey 1", "If true: throw to monkey 2");
+    assert_eq!(
+        problems(11, &mangled),
+        vec![
+            (15, "monkey 5 should be monkey 2".to_string()),
+            (19, "monkey throws to itself".to_string())
+        ]
+    );
+    assert_eq!(
+        problems(11, &example.replace("monkey 3", "monkey 4"))[0].1,
+        "throws to monkey 4, but there are only 4 monkeys"
+    );
+}
+
+#[test]
+fn rock_segments_must_be_straight() {
+    assert_eq!(
+        problems(14, "498,4 -> 498,6 -> 496,6\n503,4 -> 502,5 -> 502,9\n"),
+        vec![(
+            2,
+            "segment 503,4 -> 502,5 isn't horizontal or vertical".to_string()
+        )]
+    );
+}
+
+#[test]
+fn tunnels_lead_to_defined_valves() {
+    let data = "\
+Valve AA has flow rate=0; tunnels lead to valves DD, BB
+Valve BB has flow rate=13; tunnel leads to valve AA
+Valve BB has flow rate=2; tunnel leads to valve AA
+";
+    assert_eq!(
+        problems(16, data),
+        vec![
+            (
+                1,
+                "tunnel leads to valve DD, which isn't defined".to_string()
+            ),
+            (3, "valve BB is already defined on line 2".to_string())
+        ]
+    );
+    assert_eq!(
+        problems(16, "Valve BB has flow rate=0; tunnel leads to valve BB\n"),
+        vec![(0, "there's no starting valve AA".to_string())]
+    );
+}
@@ -0,0 +1,62 @@
+use std::fs;
+use std::path::PathBuf;
+
+use aoc2022::config::{Config, CONFIG_FILE};
+use aoc2022::days::Params;
+
+const CONFIG: &str = r#"
+data_dir = "inputs"
+profile = "alice"
+format = "json"
+
+[bench]
+iterations = 10
+
+[params.2022.day15]
+row = 10
+coord_limits = 20
+"#;
+
+#[test]
+fn anything_left_out_has_its_default() {
+    let config = Config::from_toml(CONFIG).unwrap();
+    assert_eq!(config.data_dir, PathBuf::from("inputs"));
+    assert_eq!(config.profile.as_deref(), Some("alice"));
+    assert_eq!(config.format, "json");
+    assert_eq!(config.cache_dir, Config::default().cache_dir);
+    assert_eq!(config.bench.iterations, 10);
+    assert_eq!(config.bench.warmup, 3);
+    assert_eq!(
+        config.params(2022, 15),
+        Params::new().with("row", 10).with("coord_limits", 20)
+    );
+    assert_eq!(config.params(2022, 14), Params::new());
+    assert_eq!(Config::from_toml("").unwrap(), Config::default());
+    assert_eq!(
+        Config::from_toml(&config.to_toml().unwrap()).unwrap(),
+        config
+    );
+}
+
+#[test]
+fn mistakes_are_reported() {
+    let error = |s| Config::from_toml(s).unwrap_err().to_string();
+    assert!(error("data-dir = \"inputs\"").contains("unknown field"));
+    assert!(error("[params.2022.day15]\nrows = 10").contains("no parameter \"rows\""));
+    assert!(error("[params.2022.day26]\nrow = 10").contains("2022 day 26 is not implemented"));
+}
+
+#[test]
+fn it_is_found_above_the_current_directory() {
+    let dir = std::env::temp_dir().join("aoc2022_config_test");
+    let nested = dir.join("src/year2022");
+    fs::create_dir_all(&nested).unwrap();
+    fs::write(dir.join(CONFIG_FILE), CONFIG).unwrap();
+
+    let path = Config::find(&nested).unwrap();
+    assert_eq!(path, dir.join(CONFIG_FILE));
+    let config = Config::load(&path).unwrap();
+    assert_eq!(config.data_dir, dir.join("inputs"));
+    assert_eq!(config.cache_dir, dir.join(".aoc-cache"));
+    fs::remove_dir_all(&dir).unwrap();
+}
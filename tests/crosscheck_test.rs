@@ -0,0 +1,69 @@
+use std::fs;
+use std::path::Path;
+
+use aoc2022::crosscheck::{agree, crosscheck};
+use aoc2022::days::{solver, Params};
+use aoc2022::error::SolveError;
+use aoc2022::example::all_examples;
+use aoc2022::runner::Status;
+
+#[test]
+fn every_variant_gives_the_expected_answer_for_the_examples() {
+    let mut checked = 0;
+    for example in all_examples(&Path::new(env!("CARGO_MANIFEST_DIR")).join("examples")).unwrap() {
+        let solver = solver(example.year, example.day).unwrap();
+        let data = fs::read_to_string(&example.path).unwrap();
+        for (&part, expected) in &example.expected {
+            if solver.variants(part).is_empty() {
+                continue;
+            }
+            let runs = crosscheck(solver, part, &data, &example.params);
+            assert_eq!(runs.len(), solver.variants(part).len() + 1);
+            for run in &runs {
+                let answer = match &run.outcome.status {
+                    Status::Solved(answer) => answer.to_string(),
+                    status => panic!("{:?} {:?}", run.variant, status),
+                };
+                assert_eq!(
+                    &answer, expected,
+                    "{} day {} part {} {:?}",
+                    example.year, example.day, part, run.variant
+                );
+            }
+            assert!(agree(&runs));
+            checked += 1;
+        }
+    }
+    assert!(checked >= 4);
+}
+
+#[test]
+fn a_variant_only_applies_to_the_parts_that_have_it() {
+    let day12 = solver(2022, 12).unwrap();
+    let result = day12.solve(
+        1,
+        day12.example().to_string(),
+        &Params::new().with_variant("astar"),
+    );
+    assert!(matches!(result, Err(SolveError::InvalidVariant(_))));
+
+    let day15 = solver(2022, 15).unwrap();
+    let params = Params::new().with("row", 10).with_variant("edges");
+    assert_eq!(params.for_part(day15, 1).variant(), None);
+    assert_eq!(params.for_part(day15, 2).variant(), Some("edges"));
+}
+
+#[test]
+fn variants_fail_the_same_way() {
+    let day12 = solver(2022, 12).unwrap();
+    for run in crosscheck(day12, 2, "SazE\n", &Params::new()) {
+        match &run.outcome.status {
+            Status::Failed(SolveError::Unsolvable(msg)) => assert_eq!(
+                msg, "the end is unreachable from every starting square",
+                "{:?}",
+                run.variant
+            ),
+            status => panic!("{:?} {:?}", run.variant, status),
+        }
+    }
+}
@@ -0,0 +1,314 @@
+use aoc2022::days::{latest_year, run_day_part, solver, solvers, years, Answer, Params};
+use aoc2022::error::{Location, ParseError, SolveError};
+use aoc2022::run_reader;
+
+#[test]
+fn registry_has_one_solver_per_day_in_order() {
+    let days = solvers().map(|s| (s.year(), s.day())).collect::<Vec<_>>();
+    assert!(days.windows(2).all(|pair| pair[0] < pair[1]));
+    // days and years scaffolded since are registered alongside these
+    let days_2022 = days
+        .into_iter()
+        .filter(|&(year, _)| year == 2022)
+        .collect::<Vec<_>>();
+    assert!(days_2022.starts_with(&(1..=16).map(|day| (2022, day)).collect::<Vec<_>>()));
+    assert!(years().contains(&2022));
+    assert_eq!(latest_year(), *years().last().unwrap());
+}
+
+#[test]
+fn registered_solvers_run_their_example() {
+    let day01 = solver(2022, 1).unwrap();
+    assert_eq!(
+        day01
+            .solve(1, day01.example().to_string(), &Params::new())
+            .unwrap(),
+        Answer::Int(24000)
+    );
+
+    let day02 = solver(2022, 2).unwrap();
+    assert_eq!(
+        run_day_part(2022, 2, 2, day02.example().to_string(), &Params::new()).unwrap(),
+        Answer::Int(12)
+    );
+}
+
+#[test]
+fn unimplemented_days_and_parts_are_rejected() {
+    assert!(matches!(
+        run_day_part(2022, 16, 2, String::new(), &Params::new()),
+        Err(SolveError::NotImplemented {
+            year: 2022,
+            day: 16,
+            part: 2
+        })
+    ));
+    assert!(matches!(
+        run_day_part(2022, 17, 1, String::new(), &Params::new()),
+        Err(SolveError::NotImplemented {
+            year: 2022,
+            day: 17,
+            part: 1
+        })
+    ));
+    assert!(matches!(
+        run_day_part(2021, 1, 1, String::new(), &Params::new()),
+        Err(SolveError::NotImplemented {
+            year: 2021,
+            day: 1,
+            part: 1
+        })
+    ));
+}
+
+#[test]
+fn malformed_input_is_a_parse_error() {
+    assert!(matches!(
+        run_day_part(2022, 2, 1, "A Q".to_string(), &Params::new()),
+        Err(SolveError::Parse(_))
+    ));
+    assert!(matches!(
+        run_day_part(2022, 3, 1, "1x1y".to_string(), &Params::new()),
+        Err(SolveError::Parse(_))
+    ));
+    assert!(matches!(
+        run_day_part(2022, 14, 1, "498,4 -> 497,5".to_string(), &Params::new()),
+        Err(SolveError::Parse(_))
+    ));
+}
+
+#[test]
+fn impossible_input_is_unsolvable() {
+    assert!(matches!(
+        run_day_part(2022, 6, 1, "aaaaaaaa".to_string(), &Params::new()),
+        Err(SolveError::Unsolvable(_))
+    ));
+    assert!(matches!(
+        run_day_part(
+            2022,
+            7,
+            1,
+            "$ cd /\n$ cd nowhere".to_string(),
+            &Params::new()
+        ),
+        Err(SolveError::Unsolvable(_))
+    ));
+    for part in [1, 2] {
+        assert!(matches!(
+            run_day_part(2022, 12, part, "SzE".to_string(), &Params::new()),
+            Err(SolveError::Unsolvable(_))
+        ));
+    }
+
+    for part in [1, 2] {
+        assert!(matches!(
+            run_day_part(2022, 1, part, "4294967295\n1\n".to_string(), &Params::new()),
+            Err(SolveError::Unsolvable(_))
+        ));
+        assert!(matches!(
+            run_day_part(
+                2022,
+                10,
+                part,
+                "addx 2147483647\naddx 2147483647\n".to_string(),
+                &Params::new()
+            ),
+            Err(SolveError::Unsolvable(_))
+        ));
+    }
+
+    // sand would pile up left of x = 0
+    let example = solver(2022, 14).unwrap().example();
+    assert!(matches!(
+        run_day_part(
+            2022,
+            14,
+            2,
+            example.to_string(),
+            &Params::new().with("source_x", 0)
+        ),
+        Err(SolveError::Unsolvable(_))
+    ));
+    assert!(matches!(
+        run_day_part(
+            2022,
+            14,
+            2,
+            "500,4294967295 -> 501,4294967295\n".to_string(),
+            &Params::new()
+        ),
+        Err(SolveError::Unsolvable(_))
+    ));
+
+    let far_out =
+        "Sensor at x=9223372036854775807, y=0: closest beacon is at x=-9223372036854775808, y=0\n";
+    for part in [1, 2] {
+        assert!(matches!(
+            run_day_part(2022, 15, part, far_out.to_string(), &Params::new()),
+            Err(SolveError::Unsolvable(_))
+        ));
+    }
+    assert!(matches!(
+        run_day_part(
+            2022,
+            16,
+            1,
+            "Valve AA has flow rate=4294967295; tunnel leads to valve AA\n".to_string(),
+            &Params::new()
+        ),
+        Err(SolveError::Unsolvable(_))
+    ));
+}
+
+#[test]
+fn unreachable_valves_are_left_closed() {
+    let data = "Valve AA has flow rate=0; tunnel leads to valve BB
+Valve BB has flow rate=13; tunnel leads to valve AA
+Valve CC has flow rate=20; tunnel leads to valve CC
+";
+    assert_eq!(
+        run_day_part(2022, 16, 1, data.to_string(), &Params::new()).unwrap(),
+        Answer::Int(28 * 13)
+    );
+}
+
+fn parse_error(day: u8, data: &str) -> ParseError {
+    match run_day_part(2022, day, 1, data.to_string(), &Params::new()) {
+        Err(SolveError::Parse(e)) => e,
+        other => panic!("expected a parse error, got {:?}", other),
+    }
+}
+
+#[test]
+fn parse_errors_report_line_and_column() {
+    let e = parse_error(10, "noop\naddx 3\naddx x\nnoop");
+    assert_eq!(e.message, "expected a number in instruction");
+    assert_eq!(
+        e.location,
+        Some(Location {
+            line: 3,
+            column: 6,
+            line_text: "addx x".to_string()
+        })
+    );
+
+    let e = parse_error(4, "2-4,6-8\n2-3,4-5x\n");
+    assert_eq!(e.location.unwrap().line, 2);
+
+    let e = parse_error(8, "303\n2x5\n653");
+    assert_eq!(e.location.unwrap().column, 2);
+
+    // the signal is a single line
+    let e = parse_error(6, "mjqjpqmgbljsphdztnvjfqwrcgsmlb\nzz 9\n");
+    assert_eq!(e.location.unwrap().column, 31);
+
+    let e = parse_error(8, "\n123\n456\n");
+    assert_eq!(e.location.unwrap().line, 1);
+    let e = parse_error(8, "123\n45\n");
+    assert_eq!(e.location.unwrap().line, 2);
+}
+
+#[test]
+fn trailing_garbage_is_not_silently_ignored() {
+    let e = parse_error(13, "[1,1]\n[1,2]\n\n[2]\n[3]\n\n[1\n");
+    assert_eq!(e.location.unwrap().line, 7);
+
+    let e = parse_error(7, "$ cd /\n$ ls\ndir a\n$ rm -rf a\n");
+    assert_eq!(e.location.unwrap().line, 4);
+}
+
+#[test]
+fn parse_errors_display_a_snippet() {
+    let e = parse_error(9, "R 4\nU 4\nL three\n");
+    assert_eq!(
+        e.to_string(),
+        "\
+line 3, column 3: expected a number in instruction
+  |
+3 | L three
+  |   ^"
+    );
+}
+
+#[test]
+fn parse_and_solve_steps_can_run_separately() {
+    let day11 = solver(2022, 11).unwrap();
+    let input = day11.parse(day11.example()).unwrap();
+
+    // solving mustn't consume or mutate the parsed input, so it can be solved again
+    assert_eq!(
+        day11.solve_parsed(1, &input, &Params::new()).unwrap(),
+        Answer::Int(10605)
+    );
+    assert_eq!(
+        day11.solve_parsed(1, &input, &Params::new()).unwrap(),
+        Answer::Int(10605)
+    );
+    assert_eq!(
+        day11.solve_parsed(2, &input, &Params::new()).unwrap(),
+        Answer::Int(2713310158)
+    );
+}
+
+#[test]
+fn parsed_input_is_rejected_by_other_days() {
+    let input = solver(2022, 1).unwrap().parse("1000").unwrap();
+    assert!(matches!(
+        solver(2022, 2)
+            .unwrap()
+            .solve_parsed(1, &input, &Params::new()),
+        Err(SolveError::Unsolvable(_))
+    ));
+}
+
+#[test]
+fn input_can_come_from_any_reader() {
+    let example = solver(2022, 4).unwrap().example();
+    assert_eq!(
+        run_reader(2022, 4, 1, example.as_bytes(), &Params::new()).unwrap(),
+        Answer::Int(2)
+    );
+    assert_eq!(
+        run_reader(2022, 4, 2, std::io::Cursor::new(example), &Params::new()).unwrap(),
+        Answer::Int(4)
+    );
+
+    let invalid_utf8: &[u8] = &[0xff, 0xfe];
+    assert!(matches!(
+        run_reader(2022, 4, 1, invalid_utf8, &Params::new()),
+        Err(SolveError::Io(_))
+    ));
+}
+
+#[test]
+fn params_override_a_parts_defaults() {
+    let example = solver(2022, 11).unwrap().example();
+    let params = Params::new().with("rounds", 20).with("boredom_factor", 3);
+    assert_eq!(
+        run_day_part(2022, 11, 2, example.to_string(), &params).unwrap(),
+        Answer::Int(10605)
+    );
+}
+
+#[test]
+fn invalid_params_are_rejected() {
+    let example = solver(2022, 11).unwrap().example();
+    let out_of_range = Params::new().with("rounds", -1);
+    assert!(matches!(
+        run_day_part(2022, 11, 1, example.to_string(), &out_of_range),
+        Err(SolveError::InvalidParam(_))
+    ));
+
+    // coord_limits is day 15's part 2 parameter
+    let other_parts = Params::new().with("coord_limits", 20);
+    assert!(matches!(
+        run_day_part(
+            2022,
+            15,
+            1,
+            solver(2022, 15).unwrap().example().to_string(),
+            &other_parts
+        ),
+        Err(SolveError::InvalidParam(_))
+    ));
+}
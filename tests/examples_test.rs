@@ -0,0 +1,56 @@
+use std::path::Path;
+
+use aoc2022::answers::Check;
+use aoc2022::days::solvers;
+use aoc2022::example::{all_examples, Example};
+
+fn examples() -> Vec<Example> {
+    all_examples(&Path::new(env!("CARGO_MANIFEST_DIR")).join("examples")).unwrap()
+}
+
+#[test]
+fn every_day_has_an_example() {
+    let examples = examples();
+    for solver in solvers() {
+        assert!(
+            examples
+                .iter()
+                .any(|e| (e.year, e.day) == (solver.year(), solver.day())),
+            "{} day {} has no examples",
+            solver.year(),
+            solver.day()
+        );
+    }
+}
+
+#[test]
+fn every_example_gives_its_expected_answers() {
+    let mut failures = vec![];
+    for example in examples() {
+        for (&part, expected) in &example.expected {
+            let outcome = example.run(part);
+            if example.check(&outcome) != Check::Correct {
+                failures.push(format!(
+                    "{} day {} part {} {}: expected {:?}, got {:?}",
+                    example.year, example.day, part, example.name, expected, outcome.status
+                ));
+            }
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[test]
+fn registered_examples_match_the_example_files() {
+    for example in examples().iter().filter(|e| e.name == "example") {
+        let solver = aoc2022::days::solver(example.year, example.day).unwrap();
+        let data = std::fs::read_to_string(&example.path).unwrap();
+        assert_eq!(
+            solver.example(),
+            data,
+            "{} day {}",
+            example.year,
+            example.day
+        );
+    }
+}
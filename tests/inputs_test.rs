@@ -0,0 +1,102 @@
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+
+use aoc2022::days::{Day, Year};
+use aoc2022::inputs::{Fetch, HttpFetch, InputError, InputStore};
+
+// Serves one request as adventofcode.com would, answering with `status` and `body`, and returns
+// the server's URL and the request line and headers it was sent.
+fn stub_server(status: &'static str, body: &'static str) -> (String, thread::JoinHandle<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let server = thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut request = String::new();
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            if line.trim_end().is_empty() {
+                break;
+            }
+            request.push_str(&line);
+        }
+        write!(
+            stream,
+            "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status,
+            body.len(),
+            body
+        )
+        .unwrap();
+        request
+    });
+    (url, server)
+}
+
+#[test]
+fn http_fetch_sends_the_session_token() {
+    let (url, server) = stub_server("200 OK", "1000\n2000\n");
+    let fetcher = HttpFetch::new("53cr3t").with_base_url(url.clone());
+    assert_eq!(fetcher.fetch(2022, 1).unwrap(), "1000\n2000\n");
+    assert_eq!(fetcher.source(2022, 1), format!("{}/2022/day/1/input", url));
+
+    let request = server.join().unwrap();
+    assert!(request.starts_with("GET /2022/day/1/input HTTP/1.1\r\n"));
+    assert!(request.contains("Cookie: session=53cr3t\r\n"));
+}
+
+#[test]
+fn http_fetch_reports_refused_requests() {
+    let (url, server) = stub_server("400 Bad Request", "Puzzle inputs differ by user.");
+    let fetcher = HttpFetch::new("expired").with_base_url(url);
+    let error = fetcher.fetch(2022, 2).unwrap_err();
+    assert!(matches!(&error, InputError::Fetch(msg) if msg.contains("session token")));
+    server.join().unwrap();
+}
+
+// Fetches a made up input, counting how often it's asked to.
+struct Counting(Arc<AtomicUsize>);
+
+impl Fetch for Counting {
+    fn fetch(&self, year: Year, day: Day) -> Result<String, InputError> {
+        self.0.fetch_add(1, Ordering::Relaxed);
+        Ok(format!("{} {}\n", year, day))
+    }
+
+    fn source(&self, _year: Year, _day: Day) -> String {
+        "counting".to_string()
+    }
+}
+
+#[test]
+fn missing_inputs_are_fetched_once_with_their_metadata() {
+    let dir = std::env::temp_dir().join("aoc2022_inputs_test");
+    let _ = fs::remove_dir_all(&dir);
+    let fetched = Arc::new(AtomicUsize::new(0));
+    let store = InputStore::new(&dir, Some("alice"))
+        .unwrap()
+        .with_fetcher(Counting(fetched.clone()));
+
+    let path = store.ensure(2022, 3).unwrap();
+    assert_eq!(path, dir.join("alice/2022/day03.txt"));
+    assert_eq!(fs::read_to_string(&path).unwrap(), "2022 3\n");
+    let meta = store.metadata(2022, 3).unwrap().unwrap();
+    assert_eq!(meta.source, "counting");
+    assert_eq!(meta.sha256.len(), 64);
+    assert!(meta.fetched.ends_with('Z'));
+
+    // already stored, so not fetched again, nor for another profile's missing input
+    store.ensure(2022, 3).unwrap();
+    let bob = InputStore::new(&dir, Some("bob")).unwrap();
+    assert!(!bob.ensure(2022, 3).unwrap().exists());
+    assert_eq!(bob.metadata(2022, 3).unwrap(), None);
+    assert_eq!(fetched.load(Ordering::Relaxed), 1);
+    store.fetch(2022, 3).unwrap();
+    assert_eq!(fetched.load(Ordering::Relaxed), 2);
+    fs::remove_dir_all(&dir).unwrap();
+}
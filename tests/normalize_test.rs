@@ -0,0 +1,51 @@
+use std::fs;
+use std::path::Path;
+
+use aoc2022::days::solver;
+use aoc2022::example::all_examples;
+use aoc2022::normalize_input;
+
+#[test]
+fn input_is_normalized_to_lf_with_one_trailing_newline() {
+    assert_eq!(normalize_input("1\n2\n"), "1\n2\n");
+    assert_eq!(normalize_input("1\r\n2\r\n"), "1\n2\n");
+    assert_eq!(normalize_input("\u{feff}1\n2"), "1\n2\n");
+    assert_eq!(normalize_input("1\n\n2\n\n\n"), "1\n\n2\n");
+    assert_eq!(normalize_input("  1 \n"), "  1 \n");
+    assert_eq!(normalize_input("\r\n\n"), "");
+}
+
+// The same example saved in different ways.
+fn variants(data: &str) -> Vec<(&'static str, String)> {
+    let body = data.trim_end_matches('\n');
+    vec![
+        ("crlf", data.replace('\n', "\r\n")),
+        ("bom", format!("\u{feff}{}", data)),
+        ("no trailing newline", body.to_string()),
+        ("extra trailing newlines", format!("{}\n\n\n", body)),
+        ("crlf without trailing newline", body.replace('\n', "\r\n")),
+    ]
+}
+
+#[test]
+fn every_day_gives_the_same_answers_however_its_input_is_saved() {
+    let mut failures = vec![];
+    for example in all_examples(&Path::new(env!("CARGO_MANIFEST_DIR")).join("examples")).unwrap() {
+        let solver = solver(example.year, example.day).unwrap();
+        let data = fs::read_to_string(&example.path).unwrap();
+        for (&part, expected) in &example.expected {
+            for (variant, data) in variants(&data) {
+                let actual = solver
+                    .solve(part, data, &example.params.only(solver.params(part)))
+                    .map(|answer| answer.to_string());
+                if actual.as_deref().ok() != Some(expected.as_str()) {
+                    failures.push(format!(
+                        "{} day {} part {} {} with {}: expected {:?}, got {:?}",
+                        example.year, example.day, part, example.name, variant, expected, actual
+                    ));
+                }
+            }
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
@@ -0,0 +1,186 @@
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+use aoc2022::cache::Cache;
+use aoc2022::days::{solver, Answer, Params};
+use aoc2022::report::{Record, Summary};
+use aoc2022::runner::{
+    all_parts, run_data, run_data_cached, run_parallel, run_part, InputInfo, Status,
+};
+
+#[test]
+fn all_parts_covers_every_registered_part() {
+    let parts = all_parts();
+    assert_eq!(parts.first(), Some(&(2022, 1, 1)));
+    assert!(parts.contains(&(2022, 15, 2)));
+    assert!(parts.contains(&(2022, 16, 1)));
+    assert!(!parts.contains(&(2022, 16, 2)));
+}
+
+#[test]
+fn it_solves_from_an_input_file() {
+    let path = std::env::temp_dir().join("aoc2022_runner_test_day01.txt");
+    fs::write(&path, solver(2022, 1).unwrap().example()).unwrap();
+
+    let outcome = run_part(2022, 1, 1, &path, &Params::new());
+    assert!(matches!(outcome.status, Status::Solved(Answer::Int(24000))));
+    assert!(outcome.is_ok());
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn it_skips_missing_input() {
+    let outcome = run_part(
+        2022,
+        1,
+        1,
+        Path::new("./data/does_not_exist.txt"),
+        &Params::new(),
+    );
+    assert!(matches!(outcome.status, Status::MissingInput(_)));
+    assert!(outcome.is_ok());
+}
+
+#[test]
+fn it_reports_failures_without_aborting() {
+    let outcome = run_part(
+        2022,
+        16,
+        2,
+        Path::new("./data/2022/day16.txt"),
+        &Params::new(),
+    );
+    assert!(matches!(outcome.status, Status::Failed(_)));
+    assert!(!outcome.is_ok());
+}
+
+#[test]
+fn it_solves_from_data() {
+    let data = solver(2022, 2).unwrap().example().to_string();
+    let outcome = run_data(2022, 2, 2, data, &Params::new());
+    assert!(matches!(outcome.status, Status::Solved(Answer::Int(12))));
+}
+
+#[test]
+fn it_runs_in_parallel_in_order() {
+    let targets = all_parts()
+        .into_iter()
+        .filter(|&(_, day, _)| day <= 6)
+        .collect::<Vec<_>>();
+    let outcomes = run_parallel(&targets, 4, |year, day, part| {
+        let data = solver(year, day).unwrap().example().to_string();
+        run_data(year, day, part, data, &Params::new())
+    });
+
+    let ran = outcomes
+        .iter()
+        .map(|o| (o.year, o.day, o.part))
+        .collect::<Vec<_>>();
+    assert_eq!(ran, targets);
+    assert!(outcomes
+        .iter()
+        .all(|o| matches!(o.status, Status::Solved(_))));
+}
+
+#[test]
+fn it_records_outcomes_for_machines() {
+    let path = std::env::temp_dir().join("aoc2022_runner_test_day04.txt");
+    fs::write(&path, "2-4,6-8\n").unwrap();
+
+    let record = Record::from(&run_part(2022, 4, 1, &path, &Params::new()));
+    assert_eq!(record.answer, Some(Answer::Int(0)));
+    assert_eq!(record.status, "ok");
+    assert_eq!(record.input_path, Some(path.display().to_string()));
+    // sha256 of "2-4,6-8\n"
+    assert_eq!(
+        record.input_sha256.as_deref(),
+        Some("75a9717de1195a7cbccab49178caa569279866ec5c5270b5e7e5f7cc900b912c")
+    );
+
+    let json: serde_json::Value = serde_json::from_str(&record.to_json()).unwrap();
+    assert_eq!(json["year"], 2022);
+    assert_eq!(json["day"], 4);
+    assert_eq!(json["answer"], 0);
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn it_records_failures_and_skips() {
+    let record = Record::from(&run_data(2022, 3, 1, "1x1y".to_string(), &Params::new()));
+    assert_eq!(record.status, "failed");
+    assert_eq!(record.answer, None);
+    assert_eq!(record.input_path, None);
+    assert!(record.error.unwrap().starts_with("couldn't parse input"));
+
+    let record = Record::from(&run_part(
+        2022,
+        1,
+        1,
+        Path::new("./data/does_not_exist.txt"),
+        &Params::new(),
+    ));
+    assert_eq!(record.status, "skipped");
+    assert_eq!(
+        record.to_csv(),
+        "2022,1,1,,0,,,,./data/does_not_exist.txt,,skipped,false,"
+    );
+}
+
+#[test]
+fn it_reuses_cached_answers() {
+    let dir = std::env::temp_dir().join("aoc2022_runner_test_cache");
+    let cache = Cache::new(&dir, "test");
+    cache.clear().unwrap();
+    let example = solver(2022, 10).unwrap().example().to_string();
+
+    let solved = run_data_cached(&cache, 2022, 10, 2, example.clone(), &Params::new());
+    assert!(!solved.cached);
+    let cached = run_data_cached(&cache, 2022, 10, 2, example.clone(), &Params::new());
+    assert!(cached.cached);
+    assert!(matches!(
+        (&solved.status, &cached.status),
+        (Status::Solved(a), Status::Solved(b)) if a == b
+    ));
+
+    // a different input, parameters or build is solved afresh
+    let other_input = format!("{}\n", example);
+    assert!(!run_data_cached(&cache, 2022, 10, 2, other_input, &Params::new()).cached);
+    let sha256 = InputInfo::new(None, &example).sha256;
+    assert!(cache
+        .get(2022, 10, 2, &sha256, &Params::new().with("rounds", 1))
+        .is_none());
+    assert!(cache
+        .get(2022, 10, 2, &sha256, &Params::new().with_variant("fast"))
+        .is_none());
+    assert!(Cache::new(&dir, "rebuilt")
+        .get(2022, 10, 2, &sha256, &Params::new())
+        .is_none());
+
+    // failures aren't cached
+    run_data_cached(&cache, 2022, 3, 1, "1x1y".to_string(), &Params::new());
+    assert_eq!(cache.clear().unwrap(), 2);
+    assert!(!run_data_cached(&cache, 2022, 10, 2, example, &Params::new()).cached);
+    cache.clear().unwrap();
+}
+
+#[test]
+fn it_sums_up_runs_for_machines() {
+    let outcomes = vec![
+        run_data(2022, 1, 1, "1000\n".to_string(), &Params::new()),
+        run_data(2022, 1, 2, "1000\n".to_string(), &Params::new()),
+    ];
+    let summary = Summary::new(&outcomes, 2, Duration::from_millis(3));
+    assert_eq!(summary.parts, 2);
+    assert_eq!(
+        summary.summed_part_time_ns,
+        (outcomes[0].elapsed + outcomes[1].elapsed).as_nanos()
+    );
+
+    let json: serde_json::Value = serde_json::from_str(&summary.to_json()).unwrap();
+    assert_eq!(json["summary"]["jobs"], 2);
+    assert_eq!(json["summary"]["wall_time_ns"], 3_000_000);
+    assert!(summary.to_csv().starts_with("2,2,3000000,"));
+}
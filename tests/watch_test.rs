@@ -0,0 +1,29 @@
+use std::fs;
+use std::thread;
+use std::time::Duration;
+
+use aoc2022::watch::FileWatcher;
+
+#[test]
+fn it_reports_changes_to_watched_files_only() {
+    let dir = std::env::temp_dir().join("aoc2022_watch_test");
+    fs::create_dir_all(&dir).unwrap();
+    let watched = dir.join("day01.txt");
+    let other = dir.join("day02.txt");
+    fs::write(&watched, "1000\n").unwrap();
+
+    let watcher = FileWatcher::new(std::slice::from_ref(&watched)).unwrap();
+
+    let (w, o) = (watched.clone(), other.clone());
+    let writer = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        fs::write(o, "A Y\n").unwrap();
+        fs::write(w, "2000\n").unwrap();
+    });
+
+    let changed = watcher.wait(Duration::from_millis(100)).unwrap();
+    writer.join().unwrap();
+    assert_eq!(changed, vec![watched]);
+
+    fs::remove_dir_all(&dir).unwrap();
+}